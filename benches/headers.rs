@@ -0,0 +1,101 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rsip::{
+    headers::{CallId, UntypedHeader},
+    message::HeadersExt,
+    Header, Request,
+};
+use std::convert::TryFrom;
+
+const REQUEST: &str = concat!(
+    "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n",
+    "Via: SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+    "Via: SIP/2.0/UDP ss1.atlanta.example.com;branch=z9hG4bK2d4790.1\r\n",
+    "Record-Route: <sip:ss1.atlanta.example.com;lr>\r\n",
+    "Max-Forwards: 70\r\n",
+    "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+    "To: Bob <sip:bob@biloxi.example.com>\r\n",
+    "CSeq: 1 INVITE\r\n",
+    "Contact: <sip:alice@client.atlanta.example.com;transport=tcp>\r\n",
+    "Allow: INVITE, ACK, CANCEL, OPTIONS, BYE, REFER, NOTIFY, MESSAGE, SUBSCRIBE, INFO\r\n",
+    "Supported: replaces, timer\r\n",
+    "User-Agent: rsip bench\r\n",
+    "X-Custom-One: 1\r\n",
+    "X-Custom-Two: 2\r\n",
+    "Content-Type: application/sdp\r\n",
+    "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+    "Content-Length: 0\r\n\r\n"
+);
+
+// the previous implementation: a linear scan over a `Vec<Header>`
+fn vec_call_id(headers: &[Header]) -> Option<&CallId> {
+    headers.iter().find_map(|header| match header {
+        Header::CallId(call_id) => Some(call_id),
+        _ => None,
+    })
+}
+
+fn vec_unique_push(headers: &mut Vec<Header>, header: Header) {
+    headers.retain(|s| std::mem::discriminant(s) != std::mem::discriminant(&header));
+    headers.push(header);
+}
+
+// a request with many headers, where the Call-ID is the last one
+fn large_request() -> String {
+    let (head, tail) = REQUEST.split_at(REQUEST.find("Call-ID").unwrap());
+    let extra = (0..50)
+        .map(|i| format!("X-Extra-{}: {}\r\n", i, i))
+        .collect::<String>();
+
+    format!("{}{}{}", head, extra, tail)
+}
+
+fn lookup(c: &mut Criterion) {
+    let request = Request::try_from(REQUEST).unwrap();
+    let vec: Vec<Header> = request.headers.clone().into();
+    let large = Request::try_from(large_request()).unwrap();
+    let large_vec: Vec<Header> = large.headers.clone().into();
+
+    c.bench_function("vec scan: call-id", |b| {
+        b.iter(|| vec_call_id(black_box(&vec)))
+    });
+    c.bench_function("headers index: call-id", |b| {
+        b.iter(|| black_box(&request).call_id_header())
+    });
+    c.bench_function("vec scan: call-id, large", |b| {
+        b.iter(|| vec_call_id(black_box(&large_vec)))
+    });
+    c.bench_function("headers index: call-id, large", |b| {
+        b.iter(|| black_box(&large).call_id_header())
+    });
+    c.bench_function("headers index: get by name", |b| {
+        b.iter(|| black_box(&request.headers).get("Call-ID"))
+    });
+    c.bench_function("headers index: get other by name", |b| {
+        b.iter(|| black_box(&request.headers).get("x-custom-two"))
+    });
+}
+
+fn modify(c: &mut Criterion) {
+    let request = Request::try_from(REQUEST).unwrap();
+    let vec: Vec<Header> = request.headers.clone().into();
+    let call_id: Header = CallId::new("a84b4c76e66710@pc33.atlanta.example.com").into();
+
+    c.bench_function("vec: unique_push", |b| {
+        b.iter(|| vec_unique_push(&mut vec.clone(), call_id.clone()))
+    });
+    c.bench_function("headers index: unique_push", |b| {
+        b.iter(|| request.headers.clone().unique_push(call_id.clone()))
+    });
+    c.bench_function("headers index: insert", |b| {
+        b.iter(|| request.headers.clone().insert(call_id.clone()))
+    });
+}
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("parse request", |b| {
+        b.iter(|| Request::try_from(black_box(REQUEST)))
+    });
+}
+
+criterion_group!(benches, lookup, modify, parse);
+criterion_main!(benches);
@@ -90,7 +90,7 @@ pub fn create_unauthorized_from(request: rsip::Request) -> Result<rsip::SipMessa
             realm: "atlanta.example.com".into(),
             nonce: "ea9c8e88df84f1cec4341ae6cbe5a359".into(),
             algorithm: Some(rsip::headers::auth::Algorithm::Md5),
-            qop: Some(rsip::headers::auth::Qop::Auth),
+            qop: vec![rsip::headers::auth::Qop::Auth],
             stale: Some("FALSE".into()),
             opaque: Some("".into()),
             ..Default::default()
@@ -12,6 +12,7 @@ mod untyped_header;
 #[darling(default, attributes(header))]
 struct HeaderOpts {
     display_name: Option<String>,
+    compact_name: Option<String>,
     //TODO: this should be an enum with parse trait for better safety
     integer_type: Option<String>,
 }
@@ -27,13 +28,15 @@ pub fn untyped_header_signature(item: TokenStream) -> TokenStream {
     //let field_name = field_type_name(field_type.clone());
 
     let untyped_methods = untyped_header::trait_methods(struct_name);
-    let display = untyped_header::display(struct_name, opts.display_name);
+    let names = untyped_header::names(struct_name, opts.display_name, opts.compact_name);
+    let display = untyped_header::display(struct_name);
     let into_header = untyped_header::into_header(struct_name);
     let from_into_string = untyped_header::from_into_string(struct_name);
     let from_str = untyped_header::from_str(struct_name);
 
     let expanded = quote! {
         #untyped_methods
+        #names
         #display
         #into_header
         #from_into_string
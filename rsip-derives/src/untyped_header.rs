@@ -18,19 +18,47 @@ pub fn trait_methods(struct_name: &syn::Ident) -> proc_macro2::TokenStream {
     }
 }
 
-//TODO: are we sure that we want here the {}: {} ? Maybe Header should do that
-pub fn display(struct_name: &syn::Ident, display_name: Option<String>) -> proc_macro2::TokenStream {
+pub fn names(
+    struct_name: &syn::Ident,
+    display_name: Option<String>,
+    compact_name: Option<String>,
+) -> proc_macro2::TokenStream {
     let name = match display_name {
         Some(display_name) => display_name,
         None => crate::kebab_case(struct_name.to_string()),
     };
+    let compact_name = match compact_name {
+        Some(compact_name) => quote! { Some(#compact_name) },
+        None => quote! { None },
+    };
+
+    quote! {
+        impl #struct_name {
+            /// The name of the header, as printed.
+            pub const NAME: &'static str = #name;
+            /// The compact form of the header name, if any.
+            pub const COMPACT_NAME: Option<&'static str> = #compact_name;
+        }
+    }
+}
 
+//TODO: are we sure that we want here the {}: {} ? Maybe Header should do that
+//the alternate flag (`{:#}`) is used to print the compact form of the header name, if any
+pub fn display(struct_name: &syn::Ident) -> proc_macro2::TokenStream {
     quote! {
         impl std::fmt::Display for #struct_name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 use crate::headers::untyped::UntypedHeader;
 
-                write!(f, "{}: {}", #name, self.value())
+                match f.alternate() {
+                    true => write!(
+                        f,
+                        "{}: {}",
+                        Self::COMPACT_NAME.unwrap_or(Self::NAME),
+                        self.value()
+                    ),
+                    false => write!(f, "{}: {}", Self::NAME, self.value()),
+                }
             }
         }
     }
@@ -1,10 +1,12 @@
 use rsip_derives::NewType;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Language {
     English,
     Other(OtherLanguage),
 }
 
 #[derive(NewType, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OtherLanguage(String);
@@ -8,7 +8,8 @@ macro_rules! create_methods {
     ($($name:ident),*) => {
 
         /// The SIP [Request](super::super::Request) method.
-        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Method {
             $(
                 $name,
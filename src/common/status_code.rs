@@ -10,6 +10,7 @@ macro_rules! create_status_codes {
         /// defined in any SIP RFC) status code, the reason is also provided inside the `Other`
         /// tuple variant.
         #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum StatusCode {
             $(
                 $name,
@@ -143,6 +144,7 @@ create_status_codes!(Trying => 100,
 );
 
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatusCodeKind {
     Provisional,
     Successful,
@@ -5,6 +5,7 @@ use crate::{Error, Port, Scheme};
 
 /// Simple enum that holds the transport type used (for instance in the `Via` header).
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Transport {
     Udp,
     Tcp,
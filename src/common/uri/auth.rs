@@ -4,16 +4,26 @@ pub use tokenizer::Tokenizer;
 
 /// Simple struct that holds the authority part on of a URI.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Auth {
     pub user: String,
     pub password: Option<String>,
 }
 
+/// User and password are kept unescaped and are escaped back on generation, according to the
+/// `user-unreserved` and `password` character classes of RFC3261 respectively.
 impl std::fmt::Display for Auth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use super::escaping::{escape, is_password_char, is_user_char};
+
         match &self.password {
-            Some(password) => write!(f, "{}:{}", self.user, password),
-            None => write!(f, "{}", self.user),
+            Some(password) => write!(
+                f,
+                "{}:{}",
+                escape(&self.user, is_user_char),
+                escape(password, is_password_char)
+            ),
+            None => write!(f, "{}", escape(&self.user, is_user_char)),
         }
     }
 }
@@ -35,9 +45,14 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Auth
     type Error = Error;
 
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
+        use super::escaping::unescape;
+
         Ok(Auth {
-            user: tokenizer.user.into(),
-            password: tokenizer.password.map(Into::into),
+            user: unescape(tokenizer.user.as_bytes())?,
+            password: tokenizer
+                .password
+                .map(|password| unescape(password.as_bytes()))
+                .transpose()?,
         })
     }
 }
@@ -93,37 +108,31 @@ pub mod tokenizer {
         I: AbstractInputItem<I>,
     {
         pub fn tokenize(part: T) -> GResult<T, Self> {
+            use crate::common::uri::escaping::{is_password_char, is_user_char};
             use nom::{
-                bytes::complete::{tag, take_till, take_until},
-                combinator::rest,
-                sequence::tuple,
+                bytes::complete::{tag, take_while, take_while1},
+                combinator::opt,
+                sequence::{preceded, tuple},
             };
 
-            let (rem, (auth, _)) = tuple((
-                take_till(|c| c == Into::<I>::into(b'.') || c == Into::<I>::into(b'@')),
+            let (rem, (user, password, _)) = tuple((
+                take_while1(|c: I| {
+                    let c = c.as_char();
+                    c == '%' || is_user_char(c)
+                }),
+                opt(preceded(
+                    tag(":"),
+                    take_while(|c: I| {
+                        let c = c.as_char();
+                        c == '%' || is_password_char(c)
+                    }),
+                )),
                 tag("@"),
             ))(part)
             .map_err(|_: GenericNomError<'a, T>| {
                 TokenizerError::from(("auth user", part)).into()
             })?;
 
-            let (user, password) = match tuple::<_, _, nom::error::VerboseError<T>, _>((
-                take_until(":"),
-                tag(":"),
-                rest,
-            ))(auth)
-            {
-                Ok((_, (user, _, password))) => (user, Some(password)),
-                Err(_) => {
-                    //this is not going to ever fail actually, since rest never returns an
-                    //error
-                    let (_, user) = rest(auth).map_err(|_: GenericNomError<'a, T>| {
-                        TokenizerError::from(("auth user (no password)", auth)).into()
-                    })?;
-                    (user, None)
-                }
-            };
-
             Ok((rem, Tokenizer::from((user, password))))
         }
     }
@@ -0,0 +1,79 @@
+//! Percent-encoding helpers for the parts of a SIP(S) URI that allow escaped characters, keyed to
+//! the character classes of [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-25.1).
+//!
+//! Rsip keeps [Auth](super::Auth) user and password, URI [Param](super::Param) names and values and
+//! [UriHeader](super::UriHeader) names and values unescaped, so the tokenizers use the
+//! `is_*_char` checks to find where each part ends, the parsers [unescape] and `Display` impls
+//! [escape] them back using the relevant character class.
+
+use crate::Error;
+
+/// `unreserved` characters, allowed unescaped in every part of a URI.
+pub fn is_unreserved_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-_.!~*'()".contains(c)
+}
+
+/// `unreserved` / `user-unreserved` characters, allowed unescaped in the user part.
+pub fn is_user_char(c: char) -> bool {
+    is_unreserved_char(c) || "&=+$,;?/".contains(c)
+}
+
+/// `unreserved` characters plus `&`, `=`, `+`, `$` and `,`, allowed unescaped in the password.
+pub fn is_password_char(c: char) -> bool {
+    is_unreserved_char(c) || "&=+$,".contains(c)
+}
+
+/// `unreserved` / `param-unreserved` characters, allowed unescaped in param names and values.
+pub fn is_param_char(c: char) -> bool {
+    is_unreserved_char(c) || "[]/:&+$".contains(c)
+}
+
+/// `unreserved` / `hnv-unreserved` characters, allowed unescaped in header names and values.
+pub fn is_header_char(c: char) -> bool {
+    is_unreserved_char(c) || "[]/?:+$".contains(c)
+}
+
+/// Replaces any `%HH` escape sequence in the input with the octet it represents and makes sure
+/// the result is valid UTF-8.
+pub fn unescape(part: &[u8]) -> Result<String, Error> {
+    let mut unescaped = Vec::with_capacity(part.len());
+    let mut bytes = part.iter();
+
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'%' => {
+                let hex = [
+                    *bytes.next().unwrap_or(&b' '),
+                    *bytes.next().unwrap_or(&b' '),
+                ];
+                let hex = Some(&hex)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| {
+                        Error::ParseError(format!(
+                            "invalid escape sequence in: {}",
+                            <&bstr::BStr>::from(part)
+                        ))
+                    })?;
+                unescaped.push(hex);
+            }
+            byte => unescaped.push(*byte),
+        }
+    }
+
+    Ok(String::from_utf8(unescaped).map_err(|e| e.utf8_error())?)
+}
+
+/// Escapes any octet of the input that is not allowed by the given character class, like
+/// [is_user_char].
+pub fn escape(part: &str, is_allowed: impl Fn(char) -> bool) -> String {
+    part.bytes()
+        .fold(String::with_capacity(part.len()), |mut escaped, b| {
+            match b.is_ascii() && is_allowed(char::from(b)) {
+                true => escaped.push(char::from(b)),
+                false => escaped.push_str(&format!("%{:02X}", b)),
+            };
+            escaped
+        })
+}
@@ -2,7 +2,7 @@ use crate::Error;
 use rsip_derives::NewType;
 use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 /// The `Host` enum represents the host part of the [HostWithPort](super::HostWithPort) struct
 ///
@@ -10,7 +10,13 @@ use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 ///
 /// * `Domain` that holds a [Domain] that represents a DNS domain.
 /// * `IpAddr` that holds an [IpAddr](std::net::IpAddr) and represents a raw IP address
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// An IPv6 address is generated as an IPv6 reference, in brackets (`[2001:db8::1]`), and it is
+/// parsed either with or without brackets. IPv6 references that carry a zone ID
+/// ([RFC6874](https://datatracker.ietf.org/doc/html/rfc6874), like `[fe80::1%25eth0]`) can't be
+/// represented by an [IpAddr](std::net::IpAddr), so they are kept as is, in a `Domain`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Host {
     Domain(Domain),
     IpAddr(IpAddr),
@@ -25,16 +31,14 @@ impl Default for Host {
 impl std::str::FromStr for Host {
     type Err = crate::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.parse::<IpAddr>() {
-            Ok(ip_addr) => Ok(Host::IpAddr(ip_addr)),
-            Err(_) => Ok(Host::Domain(s.into())),
-        }
+        Ok(s.into())
     }
 }
 
 /// A NewType around `String` to hold DNS domains.
 /// No check is done when you convert something into `Domain`.
 #[derive(NewType, Debug, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Domain(String);
 
 impl Hash for Domain {
@@ -53,6 +57,7 @@ impl std::fmt::Display for Host {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
             Host::Domain(domain) => write!(f, "{}", domain),
+            Host::IpAddr(IpAddr::V6(ip_addr)) => write!(f, "[{}]", ip_addr),
             Host::IpAddr(ip_addr) => write!(f, "{}", ip_addr),
         }
     }
@@ -66,7 +71,12 @@ impl From<String> for Host {
 
 impl From<&str> for Host {
     fn from(from: &str) -> Self {
-        match from.parse::<IpAddr>() {
+        let ip_addr = match from.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            Some(ipv6_reference) => ipv6_reference.parse::<Ipv6Addr>().map(IpAddr::V6),
+            None => from.parse::<IpAddr>(),
+        };
+
+        match ip_addr {
             Ok(ip_addr) => Host::IpAddr(ip_addr),
             Err(_) => Host::Domain(from.into()),
         }
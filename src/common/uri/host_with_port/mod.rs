@@ -17,7 +17,11 @@ use std::net::{IpAddr, SocketAddr};
 /// responsibility to you because you might want 5061 (TLS) as default etc.
 ///
 /// Similarly on generation, if no port is specified, no port is set at all in the final string.
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
+///
+/// IPv6 addresses are expected in their bracketed reference form, like `[2001:db8::1]:5060`, and
+/// are generated in that form as well.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HostWithPort {
     pub host: Host,
     pub port: Option<Port>,
@@ -45,12 +49,10 @@ impl TryFrom<&str> for HostWithPort {
 
     fn try_from(from: &str) -> Result<Self, Self::Error> {
         match from.rsplit_once(':') {
-            None => Ok(Host::from(from).into()),
-            Some((host, port)) => Ok((
-                Host::from(String::from(host)),
-                TryInto::<Port>::try_into(port)?,
-            )
-                .into()),
+            Some((host, port)) if !from.ends_with(']') => {
+                Ok((Host::from(host), TryInto::<Port>::try_into(port)?).into())
+            }
+            _ => Ok(Host::from(from).into()),
         }
     }
 }
@@ -131,12 +133,7 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Host
     type Error = Error;
 
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
-        use std::str::FromStr;
-
-        let host = match IpAddr::from_str(tokenizer.host) {
-            Ok(ip_addr) => Host::IpAddr(ip_addr),
-            Err(_) => Host::Domain(tokenizer.host.into()),
-        };
+        let host = Host::from(tokenizer.host);
 
         let port = match tokenizer.port {
             Some(port) => Some(port.parse::<u16>()?).map(Into::into),
@@ -200,15 +197,29 @@ pub mod tokenizer {
         pub fn tokenize(part: T) -> GResult<T, Self> {
             use nom::{
                 bytes::complete::{tag, take_till1, take_until},
-                combinator::rest,
-                sequence::tuple,
+                combinator::{opt, recognize, rest},
+                sequence::{preceded, tuple},
+            };
+
+            let is_delimiter = |c: I| {
+                c == Into::<I>::into(b';')
+                    || c == Into::<I>::into(b' ')
+                    || c == Into::<I>::into(b'?')
             };
 
+            //IPv6 reference, like [2001:db8::1]:5060
+            if let Ok((rem, (host, port))) = tuple::<_, _, nom::error::VerboseError<T>, _>((
+                recognize(tuple((tag("["), take_until("]"), tag("]")))),
+                opt(preceded(tag(":"), take_till1(is_delimiter))),
+            ))(part)
+            {
+                return Ok((rem, (host, port).into()));
+            }
+
             let (rem, host_with_port) =
-                take_till1(|c| c == Into::<I>::into(b';') || c == Into::<I>::into(b' '))(part)
-                    .map_err(|_: GenericNomError<'a, T>| {
-                        TokenizerError::from(("host with port", part)).into()
-                    })?;
+                take_till1(is_delimiter)(part).map_err(|_: GenericNomError<'a, T>| {
+                    TokenizerError::from(("host with port", part)).into()
+                })?;
 
             let (host, port) = match tuple::<_, _, nom::error::VerboseError<T>, _>((
                 take_until(":"),
@@ -1,7 +1,8 @@
 use rsip_derives::NewType;
 
 /// A simple NewType around u16, as part of the [HostWithPort](super::HostWithPort).
-#[derive(NewType, Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(NewType, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Port(u16);
 
 impl Default for Port {
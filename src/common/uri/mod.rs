@@ -1,7 +1,10 @@
 pub mod auth;
+pub mod escaping;
 pub mod host_with_port;
 pub mod param;
 pub mod scheme;
+pub mod tel_uri;
+pub mod uri_header;
 pub mod uri_with_params;
 pub mod uri_with_params_list;
 
@@ -12,10 +15,15 @@ pub use auth::Auth;
 pub use host_with_port::{Domain, Host, HostWithPort, Port};
 pub use param::Param;
 pub use scheme::Scheme;
+pub use tel_uri::{TelNumber, TelUri};
+pub use uri_header::UriHeader;
 pub use uri_with_params::UriWithParams;
 pub use uri_with_params_list::UriWithParamsList;
 
-use crate::{Error, Transport};
+use crate::{
+    error::{Locate, Unlocated},
+    Component, Error, Transport, UriPart,
+};
 use std::convert::{TryFrom, TryInto};
 
 /// A very flexible SIP(S) URI.
@@ -25,12 +33,13 @@ use std::convert::{TryFrom, TryInto};
 /// responsibility to you because you might want 5061 (TLS) as default etc.
 /// Similarly on generation, if no port is specified, no port is set at all in the final string.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Uri {
     pub scheme: Option<Scheme>,
     pub auth: Option<Auth>,
     pub host_with_port: HostWithPort,
     pub params: Vec<Param>,
-    pub headers: Vec<u32>,
+    pub headers: Vec<UriHeader>,
 }
 
 impl Uri {
@@ -53,6 +62,14 @@ impl Uri {
         })
     }
 
+    /// Returns the first uri header matching the given name (case insensitive). The value is
+    /// already percent-decoded.
+    pub fn header(&self, name: &str) -> Option<&UriHeader> {
+        self.headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+    }
+
     pub fn is_sips(&self) -> Result<bool, Error> {
         Ok(self
             .scheme
@@ -61,6 +78,93 @@ impl Uri {
             .transpose()?
             .unwrap_or(false))
     }
+
+    /// Compares two URIs according to the rules of
+    /// [RFC3261 section 19.1.4](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.4),
+    /// unlike `PartialEq` which compares each field literally. In brief:
+    ///
+    /// * scheme and host are compared case-insensitively, user and password case-sensitively,
+    ///   and escaped characters are equivalent to their unescaped form (rsip keeps them unescaped
+    ///   anyway)
+    /// * a domain never matches an IP address, and a missing port never matches an explicit one
+    /// * order of parameters and headers does not matter
+    /// * parameters found in both URIs must match (case-insensitively), a `user`, `ttl`,
+    ///   `method`, `maddr` or `transport` parameter found in only one URI never matches, while
+    ///   any other parameter found in only one URI is ignored
+    /// * headers are never ignored: all of them must be present in both URIs and match
+    pub fn rfc_eq(&self, other: &Uri) -> bool {
+        let schemes_eq = match (&self.scheme, &other.scheme) {
+            (Some(Scheme::Other(scheme)), Some(Scheme::Other(other_scheme))) => {
+                scheme.eq_ignore_ascii_case(other_scheme)
+            }
+            (scheme, other_scheme) => scheme == other_scheme,
+        };
+
+        let hosts_eq = match (self.host(), other.host()) {
+            (Host::Domain(domain), Host::Domain(other_domain)) => domain
+                .to_string()
+                .eq_ignore_ascii_case(&other_domain.to_string()),
+            (host, other_host) => host == other_host,
+        };
+
+        schemes_eq
+            && self.auth == other.auth
+            && hosts_eq
+            && self.port() == other.port()
+            && params_rfc_eq(&self.params, &other.params)
+            && headers_rfc_eq(&self.headers, &other.headers)
+    }
+}
+
+fn params_rfc_eq(params: &[Param], other_params: &[Param]) -> bool {
+    const NEVER_IGNORED: [&str; 5] = ["user", "ttl", "method", "maddr", "transport"];
+
+    let params = params.iter().map(param_parts).collect::<Vec<_>>();
+    let other_params = other_params.iter().map(param_parts).collect::<Vec<_>>();
+    let find = |params: &[(String, Option<String>)], name: &str| {
+        params
+            .iter()
+            .find(|(other_name, _)| other_name == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    let one_sided_eq = |params: &[(String, Option<String>)],
+                        other_params: &[(String, Option<String>)]| {
+        params
+            .iter()
+            .all(|(name, value)| match (value, find(other_params, name)) {
+                (Some(value), Some(Some(other_value))) => value.eq_ignore_ascii_case(&other_value),
+                (None, Some(None)) => true,
+                (_, Some(_)) => false,
+                (_, None) => !NEVER_IGNORED.contains(&name.as_str()),
+            })
+    };
+
+    one_sided_eq(&params, &other_params) && one_sided_eq(&other_params, &params)
+}
+
+//breaks a param into its (lowercased) name and its value
+fn param_parts(param: &Param) -> (String, Option<String>) {
+    let param = param.to_string();
+    let param = param.trim_start_matches(';');
+
+    match param.split_once('=') {
+        Some((name, value)) => (name.to_ascii_lowercase(), Some(value.to_string())),
+        None => (param.to_ascii_lowercase(), None),
+    }
+}
+
+fn headers_rfc_eq(headers: &[UriHeader], other_headers: &[UriHeader]) -> bool {
+    let normalize = |headers: &[UriHeader]| {
+        let mut headers = headers
+            .iter()
+            .map(|header| (header.name.to_ascii_lowercase(), header.value.clone()))
+            .collect::<Vec<_>>();
+        headers.sort();
+        headers
+    };
+
+    normalize(headers) == normalize(other_headers)
 }
 
 impl std::fmt::Display for Uri {
@@ -83,10 +187,24 @@ impl std::fmt::Display for Uri {
             self.host_with_port,
             self.params
                 .iter()
-                .map(|p| p.to_string())
+                .map(|p| p.to_uri_string())
                 .collect::<Vec<_>>()
                 .join("")
-        )
+        )?;
+
+        if !self.headers.is_empty() {
+            write!(
+                f,
+                "?{}",
+                self.headers
+                    .iter()
+                    .map(|h| h.to_string())
+                    .collect::<Vec<_>>()
+                    .join("&")
+            )?;
+        }
+
+        Ok(())
     }
 }
 
@@ -193,9 +311,14 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Uri
             params: tokenizer
                 .params
                 .into_iter()
+                .map(|param| Param::from_uri_param(param.name, param.value))
+                .collect::<Result<Vec<_>, _>>()?,
+            headers: tokenizer
+                .headers
+                .unwrap_or_default()
+                .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<Vec<_>, _>>()?,
-            headers: Default::default(),
         })
     }
 }
@@ -204,23 +327,66 @@ impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a [u8], u8>> for Uri {
     type Error = Error;
 
     fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a [u8], u8>) -> Result<Self, Self::Error> {
+        Self::convert(tokenizer, &Unlocated)
+    }
+}
+
+impl Uri {
+    pub(crate) fn convert<'a, L: Locate>(
+        tokenizer: tokenizer::Tokenizer<'a, &'a [u8], u8>,
+        locator: &L,
+    ) -> Result<Self, L::Error> {
+        use std::str::from_utf8;
+
+        let lossy = |part: &[u8]| String::from_utf8_lossy(part).into_owned();
+
         Ok(Self {
-            scheme: tokenizer.scheme.map(TryInto::try_into).transpose()?,
-            auth: tokenizer.auth.map(TryInto::try_into).transpose()?,
-            host_with_port: tokenizer.host_with_port.try_into()?,
+            scheme: tokenizer
+                .scheme
+                .map(|scheme| {
+                    locator.convert(scheme.value, || Component::Uri(UriPart::Scheme), scheme)
+                })
+                .transpose()?,
+            auth: tokenizer
+                .auth
+                .map(|auth| locator.convert(auth.user, || Component::Uri(UriPart::Auth), auth))
+                .transpose()?,
+            host_with_port: locator.convert(
+                tokenizer.host_with_port.host,
+                || Component::Uri(UriPart::HostWithPort),
+                tokenizer.host_with_port,
+            )?,
             params: tokenizer
                 .params
                 .into_iter()
-                .map(TryInto::try_into)
+                .map(|param| {
+                    let value = param.value.map(from_utf8).transpose();
+                    from_utf8(param.name)
+                        .map_err(Error::from)
+                        .and_then(|name| Param::from_uri_param(name, value?))
+                        .map_err(|error| {
+                            let component = Component::Uri(UriPart::Param(lossy(param.name)));
+                            locator.locate(param.name, component, error)
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            headers: tokenizer
+                .headers
+                .unwrap_or_default()
+                .into_iter()
+                .map(|header| {
+                    let name = header.name;
+                    let component = || Component::Uri(UriPart::Header(lossy(name)));
+                    locator.convert(name, component, header)
+                })
                 .collect::<Result<Vec<_>, _>>()?,
-            headers: Default::default(),
         })
     }
 }
 
 #[doc(hidden)]
 pub mod tokenizer {
-    use super::{auth, host_with_port, param, scheme};
+    use super::{auth, host_with_port, param, scheme, uri_header};
     use crate::{AbstractInput, AbstractInputItem, GResult, TokenizerError};
     use std::marker::PhantomData;
 
@@ -235,7 +401,7 @@ pub mod tokenizer {
         pub host_with_port: host_with_port::Tokenizer<'a, T, I>,
         pub params: Vec<param::Tokenizer<'a, T, I>>,
         //TODO: why option here?
-        pub headers: Option<Vec<T>>,
+        pub headers: Option<Vec<uri_header::Tokenizer<'a, T, I>>>,
         pub phantom1: PhantomData<&'a T>,
         pub phantom2: PhantomData<I>,
     }
@@ -247,12 +413,21 @@ pub mod tokenizer {
         TokenizerError: nom::error::ParseError<T>,
     {
         pub fn tokenize(part: T) -> GResult<T, Self> {
-            use nom::{combinator::opt, multi::many0};
+            use nom::{
+                bytes::complete::tag,
+                combinator::opt,
+                multi::{many0, separated_list1},
+                sequence::preceded,
+            };
 
             let (rem, scheme) = opt(scheme::Tokenizer::tokenize)(part)?;
             let (rem, auth) = opt(auth::Tokenizer::tokenize)(rem)?;
             let (rem, host_with_port) = host_with_port::Tokenizer::tokenize(rem)?;
-            let (rem, params) = many0(param::Tokenizer::tokenize)(rem)?;
+            let (rem, params) = many0(param::Tokenizer::tokenize_uri_param)(rem)?;
+            let (rem, headers) = opt(preceded(
+                tag("?"),
+                separated_list1(tag("&"), uri_header::Tokenizer::tokenize),
+            ))(rem)?;
 
             Ok((
                 rem,
@@ -261,8 +436,7 @@ pub mod tokenizer {
                     auth,
                     host_with_port,
                     params,
-                    //TODO: support headers in the uri
-                    headers: None,
+                    headers,
                     phantom1: Default::default(),
                     phantom2: Default::default(),
                 },
@@ -302,7 +476,7 @@ impl testing_utils::Randomize for Uri {
             auth: opt(Randomize::random()),
             host_with_port: Randomize::random(),
             params: Randomize::rand_list0(3),
-            headers: vec![],
+            headers: Randomize::rand_list0(2),
         }
     }
 }
@@ -4,6 +4,7 @@ use rsip_derives::{IntoParam, NewType};
 /// `Contact` header.
 //TODO: add typed + default
 #[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Expires(String);
 
 impl Expires {
@@ -1,10 +1,18 @@
+use crate::Host;
 use rsip_derives::{IntoParam, NewType};
 
 /// Simple NewType around String. Intended to be used for the `maddr` parameter found in the
 /// `Via` header.
 #[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Maddr(String);
 
+impl Maddr {
+    pub fn host(&self) -> Host {
+        self.0.as_str().into()
+    }
+}
+
 #[cfg(feature = "test-utils")]
 impl testing_utils::Randomize for Maddr {
     fn random() -> Self {
@@ -30,6 +30,7 @@ use std::convert::TryInto;
 /// this safety to the user.
 //TODO: move out Via/From/etc params from here, but keep the same tokenizer
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Param {
     Transport(Transport),
     User(User),
@@ -46,30 +47,78 @@ pub enum Param {
 }
 
 #[derive(NewType, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OtherParam(String);
 #[derive(NewType, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OtherParamValue(String);
 
-impl std::fmt::Display for Param {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Param {
+    /// Converts the name and value of a SIP(S) URI param, where, unlike the params of headers
+    /// like `Via` or `From`, any escaped character is unescaped.
+    pub(crate) fn from_uri_param(name: &str, value: Option<&str>) -> Result<Self, Error> {
+        let unescaped = |part: &str| match part.starts_with('"') {
+            true => Ok(part.to_string()),
+            false => super::escaping::unescape(part.as_bytes()),
+        };
+        let (name, value) = (unescaped(name)?, value.map(unescaped).transpose()?);
+
+        (name.as_str(), value.as_deref()).try_into()
+    }
+
+    /// The param as it appears in a SIP(S) URI, with its name and value escaped according to the
+    /// `param-unreserved` character class of RFC3261, apart from quoted values (like
+    /// `+sip.instance="<urn:uuid:...>"`) that are kept as is.
+    pub(crate) fn to_uri_string(&self) -> String {
+        struct Escaped<'a>(&'a Param);
+
+        impl<'a> std::fmt::Display for Escaped<'a> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                use super::escaping::{escape, is_param_char};
+
+                self.0.fmt_with(f, |value| match value.starts_with('"') {
+                    true => value.to_string(),
+                    false => escape(value, is_param_char),
+                })
+            }
+        }
+
+        Escaped(self).to_string()
+    }
+
+    fn fmt_with(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        escaped: impl Fn(&str) -> String,
+    ) -> std::fmt::Result {
         match &self {
             Self::Transport(transport) => write!(f, ";transport={}", transport),
-            Self::User(user) => write!(f, ";user={}", user),
+            Self::User(user) => write!(f, ";user={}", escaped(user.value())),
             Self::Method(method) => write!(f, ";method={}", method),
-            Self::Ttl(ttl) => write!(f, ";ttl={}", ttl),
-            Self::Maddr(maddr) => write!(f, ";maddr={}", maddr),
+            Self::Ttl(ttl) => write!(f, ";ttl={}", escaped(ttl.value())),
+            Self::Maddr(maddr) => write!(f, ";maddr={}", escaped(maddr.value())),
             Self::Lr => write!(f, ";lr"),
-            Self::Branch(branch) => write!(f, ";branch={}", branch),
-            Self::Received(received) => write!(f, ";received={}", received),
-            Self::Tag(tag) => write!(f, ";tag={}", tag),
-            Self::Expires(expires) => write!(f, ";expires={}", expires),
-            Self::Q(q) => write!(f, ";q={}", q),
-            Self::Other(name, Some(value)) => write!(f, ";{}={}", name, value),
-            Self::Other(name, None) => write!(f, ";{}", name),
+            Self::Branch(branch) => write!(f, ";branch={}", escaped(branch.value())),
+            Self::Received(received) => write!(f, ";received={}", escaped(received.value())),
+            Self::Tag(tag) => write!(f, ";tag={}", escaped(tag.value())),
+            Self::Expires(expires) => write!(f, ";expires={}", escaped(expires.value())),
+            Self::Q(q) => write!(f, ";q={}", escaped(q.value())),
+            Self::Other(name, Some(value)) => {
+                write!(f, ";{}={}", escaped(name.value()), escaped(value.value()))
+            }
+            Self::Other(name, None) => write!(f, ";{}", escaped(name.value())),
         }
     }
 }
 
+/// Params are displayed as is, which is how they appear in headers like `Via` or `From`. A
+/// [Uri](super::Uri) escapes its params when displayed instead.
+impl std::fmt::Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_with(f, |value| value.to_string())
+    }
+}
+
 impl<'a> std::convert::TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for Param {
     type Error = Error;
 
@@ -159,7 +208,30 @@ pub mod tokenizer {
         T: AbstractInput<'a, I>,
         I: AbstractInputItem<I>,
     {
+        /// Tokenizes a param of a header like `Via` or `From`, where names are tokens and values
+        /// are tokens, hosts (including IPv6 references) or quoted strings, all kept as is.
         pub fn tokenize(part: T) -> GResult<T, Self> {
+            Self::tokenize_with(part, I::is_token, |c| {
+                I::is_token(c.clone()) || "[]:".contains(c.as_char())
+            })
+        }
+
+        /// Tokenizes a param of a SIP(S) URI, where names and values may contain escaped
+        /// characters.
+        pub fn tokenize_uri_param(part: T) -> GResult<T, Self> {
+            let is_param_char = |c: I| {
+                let c = c.as_char();
+                c == '%' || crate::common::uri::escaping::is_param_char(c)
+            };
+
+            Self::tokenize_with(part, is_param_char, is_param_char)
+        }
+
+        fn tokenize_with(
+            part: T,
+            is_name_char: impl Fn(I) -> bool,
+            is_value_char: impl Fn(I) -> bool,
+        ) -> GResult<T, Self> {
             use nom::{
                 branch::alt,
                 bytes::complete::{tag, take_until, take_while},
@@ -169,13 +241,13 @@ pub mod tokenizer {
 
             let (rem, (_, name, value)) = tuple((
                 tag(";"),
-                take_while(I::is_token), //rfc3261 includes other chars as well, needs fixing..
+                take_while(is_name_char),
                 opt(map(
                     tuple((
                         tag("="),
                         alt((
                             recognize(delimited(tag("\""), take_until("\""), tag("\""))),
-                            take_while(I::is_token),
+                            take_while(is_value_char),
                         )),
                     )),
                     |t| t.1,
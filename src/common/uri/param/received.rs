@@ -3,12 +3,20 @@ use std::net::IpAddr;
 
 /// Simple NewType around String. Intended to be used for the `received` parameter found in the `Via`
 /// header.
+///
+/// RFC3261 defines it as a plain IPv6 address, but the IPv6 reference form (`[2001:db8::1]`)
+/// is widely used as well, so both are accepted.
 #[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Received(String);
 
 impl Received {
     pub fn parse(&self) -> Result<IpAddr, std::net::AddrParseError> {
-        self.0.parse()
+        self.0
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .unwrap_or(&self.0)
+            .parse()
     }
 }
 
@@ -2,7 +2,8 @@ use rsip_derives::{IntoParam, NewType};
 
 /// Simple NewType around String. Intended to be used for the `tag` parameter found in the `From`
 /// and `To` headers.
-#[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
+#[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag(String);
 
 impl<'a> Default for Tag {
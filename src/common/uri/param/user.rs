@@ -3,6 +3,7 @@ use rsip_derives::{IntoParam, NewType};
 /// Simple NewType around String. Intended to be used for the `user` parameter found in SIP(S)
 /// uris, rarely used nowardays.
 #[derive(NewType, IntoParam, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct User(String);
 
 #[cfg(feature = "test-utils")]
@@ -0,0 +1,271 @@
+use super::{Auth, Host, HostWithPort, Param, Scheme, Uri};
+use crate::Error;
+use std::convert::{TryFrom, TryInto};
+
+/// The subscriber number of a [TelUri], as defined in
+/// [RFC3966](https://datatracker.ietf.org/doc/html/rfc3966#section-3). Any visual separators
+/// (`-`, `.`, `(` and `)`) are kept as written; use [TelNumber::digits] when comparing numbers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TelNumber {
+    /// A globally unique (E.164) number like `+1-201-555-0123`, without the leading `+`.
+    Global(String),
+    /// A number that is valid only inside its `phone-context`, like `7042`.
+    Local(String),
+}
+
+impl TelNumber {
+    pub fn is_global(&self) -> bool {
+        matches!(self, Self::Global(_))
+    }
+
+    /// Returns the number without any visual separators, including the leading `+` for global
+    /// numbers.
+    pub fn digits(&self) -> String {
+        let (prefix, number) = match self {
+            Self::Global(number) => ("+", number),
+            Self::Local(number) => ("", number),
+        };
+
+        std::iter::once(prefix)
+            .chain(number.matches(|c: char| !is_visual_separator(c)))
+            .collect::<String>()
+            .to_ascii_uppercase()
+    }
+}
+
+impl std::fmt::Display for TelNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Global(number) => write!(f, "+{}", number),
+            Self::Local(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+impl std::str::FromStr for TelNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::ParseError(format!("invalid tel number: {}", s));
+
+        match s.strip_prefix('+') {
+            Some(number) => {
+                match number.chars().any(|c| c.is_ascii_digit())
+                    && number
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || is_visual_separator(c))
+                {
+                    true => Ok(Self::Global(number.into())),
+                    false => Err(invalid()),
+                }
+            }
+            None => {
+                let is_digit = |c: char| c.is_ascii_hexdigit() || c == '*' || c == '#';
+                match s.chars().any(is_digit)
+                    && s.chars().all(|c| is_digit(c) || is_visual_separator(c))
+                {
+                    true => Ok(Self::Local(s.into())),
+                    false => Err(invalid()),
+                }
+            }
+        }
+    }
+}
+
+/// A `tel` URI, as defined in [RFC3966](https://datatracker.ietf.org/doc/html/rfc3966), like
+/// `tel:+1-201-555-0123;ext=1234` or `tel:7042;phone-context=example.com`.
+///
+/// Headers like `To`/`From` and the [Request](crate::Request) line hold a [Uri], so a `TelUri`
+/// converts from/into a [Uri] with a [Scheme::Tel] scheme. It can also be converted from/into
+/// the equivalent `sip:+1-201-555-0123@example.com;user=phone` SIP URI
+/// ([RFC3261 section 19.1.6](https://datatracker.ietf.org/doc/html/rfc3261#section-19.1.6)).
+///
+/// The `npdi` and `rn` parameters come from
+/// [RFC4694](https://datatracker.ietf.org/doc/html/rfc4694), any other parameter ends up in
+/// `params`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TelUri {
+    pub number: TelNumber,
+    pub phone_context: Option<String>,
+    pub ext: Option<String>,
+    pub isub: Option<String>,
+    pub npdi: bool,
+    pub rn: Option<String>,
+    pub params: Vec<Param>,
+}
+
+impl TelUri {
+    pub fn new(number: TelNumber) -> Self {
+        Self {
+            number,
+            phone_context: None,
+            ext: None,
+            isub: None,
+            npdi: false,
+            rn: None,
+            params: vec![],
+        }
+    }
+
+    /// Converts the `tel` URI to a SIP URI pointing to the given host, like
+    /// `sip:+1-201-555-0123;ext=1234@gateway.example.com;user=phone`.
+    pub fn into_sip_uri(self, host_with_port: impl Into<HostWithPort>) -> Uri {
+        //the user is escaped on generation, hence the params are joined unescaped
+        let user = format!(
+            "{}{}",
+            self.number,
+            join(&self.ordered_params(), Param::to_string)
+        );
+
+        Uri {
+            scheme: Some(Scheme::Sip),
+            auth: Some(Auth {
+                user,
+                password: None,
+            }),
+            host_with_port: host_with_port.into(),
+            params: vec![Param::User("phone".into())],
+            headers: Default::default(),
+        }
+    }
+
+    // RFC3966 mandates that isub/ext come first, then phone-context and then the rest of the
+    // params in lexicographical order
+    fn ordered_params(&self) -> Vec<Param> {
+        let other = |name: &str, value: Option<&String>| {
+            Param::Other(name.into(), value.map(|value| value.as_str().into()))
+        };
+
+        let mut params = vec![];
+        if let Some(isub) = &self.isub {
+            params.push(other("isub", Some(isub)));
+        }
+        if let Some(ext) = &self.ext {
+            params.push(other("ext", Some(ext)));
+        }
+        if let Some(phone_context) = &self.phone_context {
+            params.push(other("phone-context", Some(phone_context)));
+        }
+
+        let mut rest = self.params.clone();
+        if self.npdi {
+            rest.push(other("npdi", None));
+        }
+        if let Some(rn) = &self.rn {
+            rest.push(other("rn", Some(rn)));
+        }
+        rest.sort_by_cached_key(|param| param.to_string().to_ascii_lowercase());
+        params.extend(rest);
+
+        params
+    }
+}
+
+impl std::fmt::Display for TelUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "tel:{}{}",
+            self.number,
+            join(&self.ordered_params(), Param::to_uri_string)
+        )
+    }
+}
+
+impl From<TelUri> for Uri {
+    fn from(tel_uri: TelUri) -> Self {
+        Self {
+            scheme: Some(Scheme::Tel),
+            auth: None,
+            host_with_port: Host::Domain(tel_uri.number.to_string().into()).into(),
+            params: tel_uri.ordered_params(),
+            headers: Default::default(),
+        }
+    }
+}
+
+/// Accepts both a `tel` URI and a SIP(S) URI with a `user=phone` parameter.
+impl TryFrom<&Uri> for TelUri {
+    type Error = Error;
+
+    fn try_from(uri: &Uri) -> Result<Self, Self::Error> {
+        match &uri.scheme {
+            Some(Scheme::Tel) => {
+                let mut tel_uri = Self::new(uri.host().to_string().parse()?);
+
+                for param in uri.params.iter().cloned() {
+                    match param {
+                        Param::Other(name, value) => {
+                            let value = value.map(|value| value.value().to_string());
+                            match (name.value().to_ascii_lowercase().as_str(), value) {
+                                ("phone-context", Some(value)) => {
+                                    tel_uri.phone_context = Some(value)
+                                }
+                                ("ext", Some(value)) => tel_uri.ext = Some(value),
+                                ("isub", Some(value)) => tel_uri.isub = Some(value),
+                                ("rn", Some(value)) => tel_uri.rn = Some(value),
+                                ("npdi", None) => tel_uri.npdi = true,
+                                (_, value) => tel_uri
+                                    .params
+                                    .push(Param::Other(name, value.map(Into::into))),
+                            }
+                        }
+                        param => tel_uri.params.push(param),
+                    }
+                }
+
+                match (&tel_uri.number, &tel_uri.phone_context) {
+                    (TelNumber::Local(_), None) => Err(Error::missing_param("phone-context")),
+                    _ => Ok(tel_uri),
+                }
+            }
+            Some(Scheme::Sip) | Some(Scheme::Sips)
+                if uri.params.iter().any(|param| {
+                    matches!(param, Param::User(user) if user.value().eq_ignore_ascii_case("phone"))
+                }) =>
+            {
+                let user = uri.user().ok_or_else(|| Error::missing_param("user"))?;
+                format!("tel:{}", user).try_into()
+            }
+            _ => Err(Error::ParseError(format!("not a tel uri: {}", uri))),
+        }
+    }
+}
+
+impl TryFrom<Uri> for TelUri {
+    type Error = Error;
+
+    fn try_from(uri: Uri) -> Result<Self, Self::Error> {
+        Self::try_from(&uri)
+    }
+}
+
+impl TryFrom<&str> for TelUri {
+    type Error = Error;
+
+    fn try_from(from: &str) -> Result<Self, Self::Error> {
+        Self::try_from(&Uri::try_from(from)?)
+    }
+}
+
+impl TryFrom<String> for TelUri {
+    type Error = Error;
+
+    fn try_from(from: String) -> Result<Self, Self::Error> {
+        Self::try_from(from.as_str())
+    }
+}
+
+fn is_visual_separator(c: char) -> bool {
+    "-.()".contains(c)
+}
+
+fn join(params: &[Param], param_to_string: impl Fn(&Param) -> String) -> String {
+    params
+        .iter()
+        .map(param_to_string)
+        .collect::<Vec<_>>()
+        .join("")
+}
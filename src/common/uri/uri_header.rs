@@ -0,0 +1,151 @@
+#[doc(hidden)]
+pub use tokenizer::Tokenizer;
+
+use super::escaping::{escape, is_header_char, unescape};
+use crate::Error;
+use std::convert::TryFrom;
+
+/// A header embedded in a SIP(S) URI, like `sip:alice@atlanta.com?Subject=project%20x`.
+///
+/// Both name and value are kept percent-decoded, so `Replaces` or `Subject` can be read as is.
+/// On generation, any character that is not allowed in a uri header (like ` `, `;`, `&`, `=` etc)
+/// is escaped again. The most common use case is the `Refer-To` header of `REFER` requests that
+/// carries a `Replaces` header
+/// ([RFC3891](https://datatracker.ietf.org/doc/html/rfc3891)) in its uri.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UriHeader {
+    pub name: String,
+    pub value: String,
+}
+
+impl UriHeader {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<N, V> From<(N, V)> for UriHeader
+where
+    N: Into<String>,
+    V: Into<String>,
+{
+    fn from(from: (N, V)) -> Self {
+        Self::new(from.0, from.1)
+    }
+}
+
+impl std::fmt::Display for UriHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}={}",
+            escape(&self.name, is_header_char),
+            escape(&self.value, is_header_char)
+        )
+    }
+}
+
+impl<'a> TryFrom<tokenizer::Tokenizer<'a, &'a str, char>> for UriHeader {
+    type Error = Error;
+
+    fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a str, char>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: unescape(tokenizer.name.as_bytes())?,
+            value: unescape(tokenizer.value.as_bytes())?,
+        })
+    }
+}
+
+impl<'a> TryFrom<tokenizer::Tokenizer<'a, &'a [u8], u8>> for UriHeader {
+    type Error = Error;
+
+    fn try_from(tokenizer: tokenizer::Tokenizer<'a, &'a [u8], u8>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: unescape(tokenizer.name)?,
+            value: unescape(tokenizer.value)?,
+        })
+    }
+}
+
+#[doc(hidden)]
+pub mod tokenizer {
+    use crate::{AbstractInput, AbstractInputItem, GResult, GenericNomError, TokenizerError};
+    use std::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct Tokenizer<'a, T, I>
+    where
+        T: AbstractInput<'a, I>,
+        I: AbstractInputItem<I>,
+    {
+        pub name: T,
+        pub value: T,
+        phantom1: PhantomData<&'a T>,
+        phantom2: PhantomData<I>,
+    }
+
+    impl<'a, T, I> From<(T, T)> for Tokenizer<'a, T, I>
+    where
+        T: AbstractInput<'a, I>,
+        I: AbstractInputItem<I>,
+    {
+        fn from(from: (T, T)) -> Self {
+            Self {
+                name: from.0,
+                value: from.1,
+                phantom1: PhantomData,
+                phantom2: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, T, I> Tokenizer<'a, T, I>
+    where
+        T: AbstractInput<'a, I>,
+        I: AbstractInputItem<I>,
+    {
+        pub fn tokenize(part: T) -> GResult<T, Self> {
+            use nom::{
+                bytes::complete::{tag, take_while, take_while1},
+                sequence::tuple,
+            };
+
+            let is_header_char = |c: I| {
+                let c = c.as_char();
+                c == '%' || super::is_header_char(c)
+            };
+
+            let (rem, (name, _, value)) = tuple((
+                take_while1(is_header_char),
+                tag("="),
+                take_while(is_header_char),
+            ))(part)
+            .map_err(|_: GenericNomError<'a, T>| {
+                TokenizerError::from(("uri header", part)).into()
+            })?;
+
+            Ok((rem, (name, value).into()))
+        }
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl testing_utils::Randomize for UriHeader {
+    fn random() -> Self {
+        use testing_utils::rand_str_of;
+
+        Self::new(rand_str_of(5), rand_str_of(8))
+    }
+}
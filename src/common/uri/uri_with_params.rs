@@ -8,6 +8,7 @@ use std::convert::TryInto;
 pub use tokenizer::Tokenizer;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UriWithParams {
     pub uri: Uri,
     pub params: Vec<Param>,
@@ -4,6 +4,7 @@ use crate::{common::uri::UriWithParams, Error};
 pub use tokenizer::Tokenizer;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UriWithParamsList(pub Vec<UriWithParams>);
 
 impl UriWithParamsList {
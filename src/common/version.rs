@@ -6,6 +6,7 @@ use std::convert::{TryFrom, TryInto};
 
 /// Simple enum that holds the SIP version. Defaults to `Version::V2`.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Version {
     V1,
     V2,
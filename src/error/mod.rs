@@ -1,5 +1,8 @@
+mod positioned_error;
 mod tokenizer_error;
 
+pub use positioned_error::{Component, ErrorKind, PositionedError, UriPart};
+pub(crate) use positioned_error::{Locate, Locator, TokenizeFailure, Unlocated};
 pub use tokenizer_error::TokenizerError;
 
 use std::{error::Error as StdError, fmt};
@@ -23,6 +26,9 @@ use std::{error::Error as StdError, fmt};
 /// get the information regarding which header had this issue.
 /// * `Unexpected` indicates any other error.
 ///
+/// To find out where in a SIP message a parsing error occurred, parse it through
+/// [Request::parse](crate::Request::parse) and friends, which return a [PositionedError].
+///
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum Error {
     MissingHeader(String),
@@ -0,0 +1,245 @@
+use super::{Error, TokenizerError};
+use std::{convert::TryInto, error::Error as StdError, fmt};
+
+/// The kind of a [PositionedError]:
+/// * `Tokenize`: the message could not be broken into its parts, like the start line or the
+///   headers.
+/// * `TrailingInput`: a part was tokenized but some input was left over that could not be
+///   tokenized, like a malformed header line.
+/// * `Parse`: a part was tokenized but could not be converted to its type, like an unknown method.
+/// * `Utf8`: a part was not valid utf8.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorKind {
+    Tokenize,
+    TrailingInput,
+    Parse,
+    Utf8,
+}
+
+/// The part of the [Uri] where a [PositionedError] occurred, holding the name of the param or the
+/// header when that is known.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum UriPart {
+    Scheme,
+    Auth,
+    HostWithPort,
+    Param(String),
+    Header(String),
+}
+
+/// The component of a SIP message where a [PositionedError] occurred. `StartLine` and `Headers`
+/// are used when the error could not be narrowed down any further.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Component {
+    StartLine,
+    Method,
+    Uri(UriPart),
+    Version,
+    StatusCode,
+    Header(String),
+    Headers,
+}
+
+/// A parse error along with its position in the input, returned by
+/// [Request::parse](crate::Request::parse), [Response::parse](crate::Response::parse) and
+/// [SipMessage::parse](crate::SipMessage::parse).
+///
+/// The `offset` is the byte offset in the input, while `line` and `column` are 1-based, with the
+/// column counted in bytes. The `error` is the same [Error] that the `TryFrom` conversion of the
+/// [Request](crate::Request) or [Response](crate::Response) returns for that input, and the
+/// `Display` implementation of the `PositionedError` starts with it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PositionedError {
+    pub kind: ErrorKind,
+    pub component: Component,
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+    pub error: Error,
+}
+
+impl fmt::Display for UriPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Scheme => write!(f, "scheme"),
+            Self::Auth => write!(f, "auth"),
+            Self::HostWithPort => write!(f, "host with port"),
+            Self::Param(name) => write!(f, "param {}", name),
+            Self::Header(name) => write!(f, "header {}", name),
+        }
+    }
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::StartLine => write!(f, "start line"),
+            Self::Method => write!(f, "method"),
+            Self::Uri(part) => write!(f, "uri {}", part),
+            Self::Version => write!(f, "version"),
+            Self::StatusCode => write!(f, "status code"),
+            Self::Header(name) => write!(f, "header {}", name),
+            Self::Headers => write!(f, "headers"),
+        }
+    }
+}
+
+impl fmt::Display for PositionedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} (at line {}, column {}, in {}",
+            self.error, self.line, self.column, self.component
+        )?;
+        if self.kind == ErrorKind::TrailingInput {
+            write!(f, ", trailing input")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl StdError for PositionedError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<PositionedError> for Error {
+    fn from(from: PositionedError) -> Self {
+        from.error
+    }
+}
+
+// Attributes the error of a part of a message to the component it belongs to and the position of
+// the part in the input. The conversions of the message tokenizers go through it, so that both
+// the `TryFrom` conversions (through `Unlocated`, which keeps the error as is) and `parse`
+// (through `Locator`) share the same path.
+pub(crate) trait Locate {
+    type Error;
+
+    fn locate(&self, part: &[u8], component: Component, error: Error) -> Self::Error;
+
+    fn convert<T, U>(
+        &self,
+        part: &[u8],
+        component: impl FnOnce() -> Component,
+        from: T,
+    ) -> Result<U, Self::Error>
+    where
+        T: TryInto<U, Error = Error>,
+    {
+        from.try_into()
+            .map_err(|error| self.locate(part, component(), error))
+    }
+}
+
+pub(crate) struct Unlocated;
+
+impl Locate for Unlocated {
+    type Error = Error;
+
+    fn locate(&self, _: &[u8], _: Component, error: Error) -> Error {
+        error
+    }
+}
+
+// A tokenizer error of a message, along with the part of the input that failed to tokenize (or
+// was left over) and the component of the message it belongs to.
+pub(crate) struct TokenizeFailure<'a> {
+    pub part: &'a [u8],
+    pub component: Component,
+    pub trailing: bool,
+    pub error: nom::Err<TokenizerError>,
+}
+
+impl<'a> TokenizeFailure<'a> {
+    pub fn of(
+        part: &'a [u8],
+        component: Component,
+    ) -> impl FnOnce(nom::Err<TokenizerError>) -> Self {
+        move |error| Self {
+            part,
+            component,
+            trailing: false,
+            error,
+        }
+    }
+
+    pub fn trailing(
+        part: &'a [u8],
+        component: Component,
+    ) -> impl FnOnce(nom::Err<TokenizerError>) -> Self {
+        move |error| Self {
+            trailing: true,
+            ..Self::of(part, component)(error)
+        }
+    }
+}
+
+// Locates the errors of the parts of a message in its input. The tokenizers only hold slices of
+// the input, so the position of any part is found by its address.
+pub(crate) struct Locator<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Locate for Locator<'a> {
+    type Error = PositionedError;
+
+    fn locate(&self, part: &[u8], component: Component, error: Error) -> PositionedError {
+        let kind = match error {
+            Error::TokenizeError(_) => ErrorKind::Tokenize,
+            Error::Utf8Error(_) => ErrorKind::Utf8,
+            _ => ErrorKind::Parse,
+        };
+
+        self.positioned(part, kind, component, error)
+    }
+}
+
+impl<'a> Locator<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    pub fn tokenizer(&self, failure: TokenizeFailure) -> PositionedError {
+        let kind = match failure.trailing {
+            true => ErrorKind::TrailingInput,
+            false => ErrorKind::Tokenize,
+        };
+
+        self.positioned(failure.part, kind, failure.component, failure.error.into())
+    }
+
+    fn positioned(
+        &self,
+        part: &[u8],
+        kind: ErrorKind,
+        component: Component,
+        error: Error,
+    ) -> PositionedError {
+        let offset = self.offset(part.as_ptr() as usize);
+        let before = &self.input[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|c| *c == b'\n')
+            .map(|position| position + 1)
+            .unwrap_or(0);
+
+        PositionedError {
+            kind,
+            component,
+            offset,
+            line: before.iter().filter(|c| **c == b'\n').count() + 1,
+            column: offset - line_start + 1,
+            error,
+        }
+    }
+
+    // an address outside of the input can't happen, but it is safer to point to the end of it
+    fn offset(&self, address: usize) -> usize {
+        address
+            .checked_sub(self.input.as_ptr() as usize)
+            .filter(|offset| *offset <= self.input.len())
+            .unwrap_or(self.input.len())
+    }
+}
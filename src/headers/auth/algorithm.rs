@@ -1,12 +1,21 @@
 /// The `Algorithm`, as part of the SIP Authorization framework, found in headers like
 /// [Authorization](super::super::typed::Authorization) and
 /// [WwwAuthenticate](super::super::typed::WwwAuthenticate)
+///
+/// The tokens are the ones registered by [RFC7616](https://datatracker.ietf.org/doc/html/rfc7616)
+/// (like `SHA-256` and `SHA-512-256-sess`), while the older `SHA256` and `SHA256-sess` spellings
+/// are accepted as well. `Sha512` and `Sha512Sess` (`SHA512` and `SHA512-sess`) are not part of
+/// any RFC, they hash with the full SHA-512 and are kept only to interoperate with peers that
+/// used them.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Algorithm {
     Md5,
     Md5Sess,
     Sha256,
     Sha256Sess,
+    Sha512256,
+    Sha512256Sess,
     Sha512,
     Sha512Sess,
 }
@@ -22,8 +31,10 @@ impl std::fmt::Display for Algorithm {
         match self {
             Self::Md5 => write!(f, "MD5"),
             Self::Md5Sess => write!(f, "MD5-sess"),
-            Self::Sha256 => write!(f, "SHA256"),
-            Self::Sha256Sess => write!(f, "SHA256-sess"),
+            Self::Sha256 => write!(f, "SHA-256"),
+            Self::Sha256Sess => write!(f, "SHA-256-sess"),
+            Self::Sha512256 => write!(f, "SHA-512-256"),
+            Self::Sha512256Sess => write!(f, "SHA-512-256-sess"),
             Self::Sha512 => write!(f, "SHA512"),
             Self::Sha512Sess => write!(f, "SHA512-sess"),
         }
@@ -47,8 +58,12 @@ impl std::convert::TryFrom<&str> for Algorithm {
         match s {
             s if s.eq_ignore_ascii_case("md5") => Ok(Self::Md5),
             s if s.eq_ignore_ascii_case("md5-sess") => Ok(Self::Md5Sess),
+            s if s.eq_ignore_ascii_case("sha-256") => Ok(Self::Sha256),
+            s if s.eq_ignore_ascii_case("sha-256-sess") => Ok(Self::Sha256Sess),
             s if s.eq_ignore_ascii_case("sha256") => Ok(Self::Sha256),
             s if s.eq_ignore_ascii_case("sha256-sess") => Ok(Self::Sha256Sess),
+            s if s.eq_ignore_ascii_case("sha-512-256") => Ok(Self::Sha512256),
+            s if s.eq_ignore_ascii_case("sha-512-256-sess") => Ok(Self::Sha512256Sess),
             s if s.eq_ignore_ascii_case("sha512") => Ok(Self::Sha512),
             s if s.eq_ignore_ascii_case("sha512-sess") => Ok(Self::Sha512Sess),
             s => Err(crate::Error::ParseError(format!(
@@ -2,6 +2,7 @@
 /// [Authorization](super::super::typed::Authorization) and
 /// [WwwAuthenticate](super::super::typed::WwwAuthenticate)
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Qop {
     Auth,
     AuthInt,
@@ -39,19 +40,20 @@ impl std::convert::TryFrom<&str> for Qop {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AuthQop {
-    Auth { cnonce: String, nc: u8 },
-    AuthInt { cnonce: String, nc: u8 },
+    Auth { cnonce: String, nc: u32 },
+    AuthInt { cnonce: String, nc: u32 },
 }
 
 impl std::fmt::Display for AuthQop {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Auth { cnonce, nc } => {
-                write!(f, "qop=\"auth\", nc={:08}, cnonce=\"{}\"", nc, cnonce)
+                write!(f, "qop=\"auth\", nc={:08x}, cnonce=\"{}\"", nc, cnonce)
             }
             Self::AuthInt { cnonce, nc } => {
-                write!(f, "qop=\"auth-int\", nc={:08}, cnonce=\"{}\"", nc, cnonce)
+                write!(f, "qop=\"auth-int\", nc={:08x}, cnonce=\"{}\"", nc, cnonce)
             }
         }
     }
@@ -5,6 +5,7 @@ pub use tokenizer::Tokenizer;
 /// [Authorization](super::super::typed::Authorization) and
 /// [WwwAuthenticate](super::super::typed::WwwAuthenticate)
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scheme {
     Digest,
     Other(String),
@@ -0,0 +1,90 @@
+use super::{Header, Headers, Key};
+
+/// A view into the headers of a single kind, returned by [Headers::entry].
+#[derive(Debug)]
+pub enum Entry<'a> {
+    Occupied(OccupiedEntry<'a>),
+    Vacant(VacantEntry<'a>),
+}
+
+/// The headers of a kind that exists in [Headers].
+#[derive(Debug)]
+pub struct OccupiedEntry<'a> {
+    pub(super) headers: &'a mut Headers,
+    pub(super) key: Key,
+}
+
+/// A kind of headers that doesn't exist in [Headers].
+#[derive(Debug)]
+pub struct VacantEntry<'a> {
+    pub(super) headers: &'a mut Headers,
+}
+
+impl<'a> Entry<'a> {
+    /// Returns the first header of the entry, pushing the given header if the entry is vacant.
+    pub fn or_insert(self, header: Header) -> &'a mut Header {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(header),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> Header>(self, f: F) -> &'a mut Header {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+}
+
+impl<'a> OccupiedEntry<'a> {
+    /// Returns the first header of the entry.
+    pub fn get(&self) -> &Header {
+        self.iter().next().expect("occupied entry without headers")
+    }
+
+    pub fn get_mut(&mut self) -> &mut Header {
+        self.headers
+            .lookup_mut_untracked(&self.key)
+            .expect("occupied entry without headers")
+    }
+
+    pub fn into_mut(self) -> &'a mut Header {
+        self.headers
+            .lookup_mut_untracked(&self.key)
+            .expect("occupied entry without headers")
+    }
+
+    /// Returns all headers of the entry, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Header> {
+        self.headers.lookup(&self.key)
+    }
+
+    /// Replaces the headers of the entry with the given one, like [Headers::insert], returning the
+    /// replaced headers.
+    pub fn insert(&mut self, header: Header) -> Vec<Header> {
+        self.headers.insert(header)
+    }
+
+    /// Pushes the given header at the end of the headers.
+    pub fn append(&mut self, header: Header) {
+        self.headers.push(header)
+    }
+
+    /// Removes all headers of the entry, returning them in order.
+    pub fn remove(self) -> Vec<Header> {
+        self.headers.remove_key(&self.key)
+    }
+}
+
+impl<'a> VacantEntry<'a> {
+    /// Pushes the given header at the end of the headers.
+    pub fn insert(self, header: Header) -> &'a mut Header {
+        self.headers.push(header);
+        self.headers.stale = true;
+        self.headers
+            .headers
+            .last_mut()
+            .expect("headers are empty after a push")
+    }
+}
@@ -13,13 +13,30 @@ pub use tokenizer::Tokenizer;
 /// ```
 /// let x_fs_sending_message = rsip::Header::Other("X-FS-Sending-Message".into(), "f9c4adc8-9c2a-47d5-a7f1-63d20784685e".into());
 /// ```
+///
+/// The `Invalid` variant holds a raw header line that could not be tokenized, which only
+/// [lenient parsing](crate::message::ParseOptions) produces. It is printed as is.
+///
+/// Headers that have a compact form (like `v` for `Via` or `i` for `Call-ID`), as defined in
+/// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261#section-7.3.3) and IANA's SIP header
+/// registry, are parsed to their relevant variant. Printing a header using the alternate flag
+/// (`{:#}`) uses the compact form:
+/// ```
+/// use rsip::headers::UntypedHeader;
+///
+/// let call_id: rsip::Header = rsip::headers::CallId::new("1j9FpLxk3uxtm8tn").into();
+/// assert_eq!(format!("{:#}", call_id), "i: 1j9FpLxk3uxtm8tn");
+/// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Header {
     Accept(Accept),
+    AcceptContact(AcceptContact),
     AcceptEncoding(AcceptEncoding),
     AcceptLanguage(AcceptLanguage),
     AlertInfo(AlertInfo),
     Allow(Allow),
+    AllowEvents(AllowEvents),
     AuthenticationInfo(AuthenticationInfo),
     Authorization(Authorization),
     CSeq(CSeq),
@@ -36,7 +53,10 @@ pub enum Header {
     Event(Event),
     Expires(Expires),
     From(From),
+    Identity(Identity),
+    IdentityInfo(IdentityInfo),
     InReplyTo(InReplyTo),
+    Invalid(String),
     MaxForwards(MaxForwards),
     MimeVersion(MimeVersion),
     MinExpires(MinExpires),
@@ -47,11 +67,16 @@ pub enum Header {
     ProxyAuthorization(ProxyAuthorization),
     ProxyRequire(ProxyRequire),
     RecordRoute(RecordRoute),
+    ReferTo(ReferTo),
+    ReferredBy(ReferredBy),
+    RejectContact(RejectContact),
     ReplyTo(ReplyTo),
+    RequestDisposition(RequestDisposition),
     Require(Require),
     RetryAfter(RetryAfter),
     Route(Route),
     Server(Server),
+    SessionExpires(SessionExpires),
     Subject(Subject),
     SubscriptionState(SubscriptionState),
     Supported(Supported),
@@ -64,56 +89,131 @@ pub enum Header {
     WwwAuthenticate(WwwAuthenticate),
 }
 
+impl Header {
+    /// The name of the header, as printed, which is not known for an `Invalid` header.
+    pub(crate) fn name(&self) -> Option<&str> {
+        match self {
+            Self::Accept(_) => Some(Accept::NAME),
+            Self::AcceptContact(_) => Some(AcceptContact::NAME),
+            Self::AcceptEncoding(_) => Some(AcceptEncoding::NAME),
+            Self::AcceptLanguage(_) => Some(AcceptLanguage::NAME),
+            Self::AlertInfo(_) => Some(AlertInfo::NAME),
+            Self::Allow(_) => Some(Allow::NAME),
+            Self::AllowEvents(_) => Some(AllowEvents::NAME),
+            Self::AuthenticationInfo(_) => Some(AuthenticationInfo::NAME),
+            Self::Authorization(_) => Some(Authorization::NAME),
+            Self::CSeq(_) => Some(CSeq::NAME),
+            Self::CallId(_) => Some(CallId::NAME),
+            Self::CallInfo(_) => Some(CallInfo::NAME),
+            Self::Contact(_) => Some(Contact::NAME),
+            Self::ContentDisposition(_) => Some(ContentDisposition::NAME),
+            Self::ContentEncoding(_) => Some(ContentEncoding::NAME),
+            Self::ContentLanguage(_) => Some(ContentLanguage::NAME),
+            Self::ContentLength(_) => Some(ContentLength::NAME),
+            Self::ContentType(_) => Some(ContentType::NAME),
+            Self::Date(_) => Some(Date::NAME),
+            Self::ErrorInfo(_) => Some(ErrorInfo::NAME),
+            Self::Event(_) => Some(Event::NAME),
+            Self::Expires(_) => Some(Expires::NAME),
+            Self::From(_) => Some(From::NAME),
+            Self::Identity(_) => Some(Identity::NAME),
+            Self::IdentityInfo(_) => Some(IdentityInfo::NAME),
+            Self::InReplyTo(_) => Some(InReplyTo::NAME),
+            Self::Invalid(_) => None,
+            Self::MaxForwards(_) => Some(MaxForwards::NAME),
+            Self::MimeVersion(_) => Some(MimeVersion::NAME),
+            Self::MinExpires(_) => Some(MinExpires::NAME),
+            Self::Organization(_) => Some(Organization::NAME),
+            Self::Other(name, _) => Some(name),
+            Self::Priority(_) => Some(Priority::NAME),
+            Self::ProxyAuthenticate(_) => Some(ProxyAuthenticate::NAME),
+            Self::ProxyAuthorization(_) => Some(ProxyAuthorization::NAME),
+            Self::ProxyRequire(_) => Some(ProxyRequire::NAME),
+            Self::RecordRoute(_) => Some(RecordRoute::NAME),
+            Self::ReferTo(_) => Some(ReferTo::NAME),
+            Self::ReferredBy(_) => Some(ReferredBy::NAME),
+            Self::RejectContact(_) => Some(RejectContact::NAME),
+            Self::ReplyTo(_) => Some(ReplyTo::NAME),
+            Self::RequestDisposition(_) => Some(RequestDisposition::NAME),
+            Self::Require(_) => Some(Require::NAME),
+            Self::RetryAfter(_) => Some(RetryAfter::NAME),
+            Self::Route(_) => Some(Route::NAME),
+            Self::Server(_) => Some(Server::NAME),
+            Self::SessionExpires(_) => Some(SessionExpires::NAME),
+            Self::Subject(_) => Some(Subject::NAME),
+            Self::SubscriptionState(_) => Some(SubscriptionState::NAME),
+            Self::Supported(_) => Some(Supported::NAME),
+            Self::Timestamp(_) => Some(Timestamp::NAME),
+            Self::To(_) => Some(To::NAME),
+            Self::Unsupported(_) => Some(Unsupported::NAME),
+            Self::UserAgent(_) => Some(UserAgent::NAME),
+            Self::Via(_) => Some(Via::NAME),
+            Self::Warning(_) => Some(Warning::NAME),
+            Self::WwwAuthenticate(_) => Some(WwwAuthenticate::NAME),
+        }
+    }
+}
+
 impl std::fmt::Display for Header {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Accept(inner) => write!(f, "{}", inner),
-            Self::AcceptEncoding(inner) => write!(f, "{}", inner),
-            Self::AcceptLanguage(inner) => write!(f, "{}", inner),
-            Self::AlertInfo(inner) => write!(f, "{}", inner),
-            Self::Allow(inner) => write!(f, "{}", inner),
-            Self::AuthenticationInfo(inner) => write!(f, "{}", inner),
-            Self::Authorization(inner) => write!(f, "{}", inner),
-            Self::CSeq(inner) => write!(f, "{}", inner),
-            Self::CallId(inner) => write!(f, "{}", inner),
-            Self::CallInfo(inner) => write!(f, "{}", inner),
-            Self::Contact(inner) => write!(f, "{}", inner),
-            Self::ContentDisposition(inner) => write!(f, "{}", inner),
-            Self::ContentEncoding(inner) => write!(f, "{}", inner),
-            Self::ContentLanguage(inner) => write!(f, "{}", inner),
-            Self::ContentLength(inner) => write!(f, "{}", inner),
-            Self::ContentType(inner) => write!(f, "{}", inner),
-            Self::Date(inner) => write!(f, "{}", inner),
-            Self::ErrorInfo(inner) => write!(f, "{}", inner),
-            Self::Event(inner) => write!(f, "{}", inner),
-            Self::Expires(inner) => write!(f, "{}", inner),
-            Self::From(inner) => write!(f, "{}", inner),
-            Self::InReplyTo(inner) => write!(f, "{}", inner),
-            Self::MaxForwards(inner) => write!(f, "{}", inner),
-            Self::MimeVersion(inner) => write!(f, "{}", inner),
-            Self::MinExpires(inner) => write!(f, "{}", inner),
-            Self::Organization(inner) => write!(f, "{}", inner),
+            Self::Accept(inner) => inner.fmt(f),
+            Self::AcceptContact(inner) => inner.fmt(f),
+            Self::AcceptEncoding(inner) => inner.fmt(f),
+            Self::AcceptLanguage(inner) => inner.fmt(f),
+            Self::AlertInfo(inner) => inner.fmt(f),
+            Self::Allow(inner) => inner.fmt(f),
+            Self::AllowEvents(inner) => inner.fmt(f),
+            Self::AuthenticationInfo(inner) => inner.fmt(f),
+            Self::Authorization(inner) => inner.fmt(f),
+            Self::CSeq(inner) => inner.fmt(f),
+            Self::CallId(inner) => inner.fmt(f),
+            Self::CallInfo(inner) => inner.fmt(f),
+            Self::Contact(inner) => inner.fmt(f),
+            Self::ContentDisposition(inner) => inner.fmt(f),
+            Self::ContentEncoding(inner) => inner.fmt(f),
+            Self::ContentLanguage(inner) => inner.fmt(f),
+            Self::ContentLength(inner) => inner.fmt(f),
+            Self::ContentType(inner) => inner.fmt(f),
+            Self::Date(inner) => inner.fmt(f),
+            Self::ErrorInfo(inner) => inner.fmt(f),
+            Self::Event(inner) => inner.fmt(f),
+            Self::Expires(inner) => inner.fmt(f),
+            Self::From(inner) => inner.fmt(f),
+            Self::Identity(inner) => inner.fmt(f),
+            Self::IdentityInfo(inner) => inner.fmt(f),
+            Self::InReplyTo(inner) => inner.fmt(f),
+            Self::Invalid(raw) => write!(f, "{}", raw),
+            Self::MaxForwards(inner) => inner.fmt(f),
+            Self::MimeVersion(inner) => inner.fmt(f),
+            Self::MinExpires(inner) => inner.fmt(f),
+            Self::Organization(inner) => inner.fmt(f),
             Self::Other(key, value) => write!(f, "{}: {}", key, value),
-            Self::Priority(inner) => write!(f, "{}", inner),
-            Self::ProxyAuthenticate(inner) => write!(f, "{}", inner),
-            Self::ProxyAuthorization(inner) => write!(f, "{}", inner),
-            Self::ProxyRequire(inner) => write!(f, "{}", inner),
-            Self::RecordRoute(inner) => write!(f, "{}", inner),
-            Self::ReplyTo(inner) => write!(f, "{}", inner),
-            Self::Require(inner) => write!(f, "{}", inner),
-            Self::RetryAfter(inner) => write!(f, "{}", inner),
-            Self::Route(inner) => write!(f, "{}", inner),
-            Self::Server(inner) => write!(f, "{}", inner),
-            Self::Subject(inner) => write!(f, "{}", inner),
-            Self::SubscriptionState(inner) => write!(f, "{}", inner),
-            Self::Supported(inner) => write!(f, "{}", inner),
-            Self::Timestamp(inner) => write!(f, "{}", inner),
-            Self::To(inner) => write!(f, "{}", inner),
-            Self::Unsupported(inner) => write!(f, "{}", inner),
-            Self::UserAgent(inner) => write!(f, "{}", inner),
-            Self::Via(inner) => write!(f, "{}", inner),
-            Self::Warning(inner) => write!(f, "{}", inner),
-            Self::WwwAuthenticate(inner) => write!(f, "{}", inner),
+            Self::Priority(inner) => inner.fmt(f),
+            Self::ProxyAuthenticate(inner) => inner.fmt(f),
+            Self::ProxyAuthorization(inner) => inner.fmt(f),
+            Self::ProxyRequire(inner) => inner.fmt(f),
+            Self::RecordRoute(inner) => inner.fmt(f),
+            Self::ReferTo(inner) => inner.fmt(f),
+            Self::ReferredBy(inner) => inner.fmt(f),
+            Self::RejectContact(inner) => inner.fmt(f),
+            Self::ReplyTo(inner) => inner.fmt(f),
+            Self::RequestDisposition(inner) => inner.fmt(f),
+            Self::Require(inner) => inner.fmt(f),
+            Self::RetryAfter(inner) => inner.fmt(f),
+            Self::Route(inner) => inner.fmt(f),
+            Self::Server(inner) => inner.fmt(f),
+            Self::SessionExpires(inner) => inner.fmt(f),
+            Self::Subject(inner) => inner.fmt(f),
+            Self::SubscriptionState(inner) => inner.fmt(f),
+            Self::Supported(inner) => inner.fmt(f),
+            Self::Timestamp(inner) => inner.fmt(f),
+            Self::To(inner) => inner.fmt(f),
+            Self::Unsupported(inner) => inner.fmt(f),
+            Self::UserAgent(inner) => inner.fmt(f),
+            Self::Via(inner) => inner.fmt(f),
+            Self::Warning(inner) => inner.fmt(f),
+            Self::WwwAuthenticate(inner) => inner.fmt(f),
         }
     }
 }
@@ -121,7 +221,10 @@ impl std::fmt::Display for Header {
 #[doc(hidden)]
 pub mod tokenizer {
     use super::*;
-    use crate::{Error, IResult, TokenizerError};
+    use crate::{
+        error::{Locate, TokenizeFailure},
+        Component, Error, IResult, TokenizerError,
+    };
     use rsip_derives::Utf8Tokenizer;
     use std::convert::TryInto;
 
@@ -130,11 +233,19 @@ pub mod tokenizer {
 
         fn try_into(self) -> Result<Header, Error> {
             let tokenizer: Utf8Tokenizer = self.try_into()?;
+            let value = unfold(tokenizer.value);
+            let tokenizer = Utf8Tokenizer {
+                name: tokenizer.name,
+                value: &value,
+            };
 
             match tokenizer.name {
                 s if s.eq_ignore_ascii_case("Accept") => {
                     Ok(Header::Accept(Accept::new(tokenizer.value)))
                 }
+                s if s.eq_ignore_ascii_case("Accept-Contact") || s.eq_ignore_ascii_case("a") => {
+                    Ok(Header::AcceptContact(AcceptContact::new(tokenizer.value)))
+                }
                 s if s.eq_ignore_ascii_case("Accept-Encoding") => {
                     Ok(Header::AcceptEncoding(AcceptEncoding::new(tokenizer.value)))
                 }
@@ -147,6 +258,9 @@ pub mod tokenizer {
                 s if s.eq_ignore_ascii_case("Allow") => {
                     Ok(Header::Allow(Allow::new(tokenizer.value)))
                 }
+                s if s.eq_ignore_ascii_case("Allow-Events") || s.eq_ignore_ascii_case("u") => {
+                    Ok(Header::AllowEvents(AllowEvents::new(tokenizer.value)))
+                }
                 s if s.eq_ignore_ascii_case("Authentication-Info") => Ok(
                     Header::AuthenticationInfo(AuthenticationInfo::new(tokenizer.value)),
                 ),
@@ -154,41 +268,51 @@ pub mod tokenizer {
                     Ok(Header::Authorization(Authorization::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("CSeq") => Ok(Header::CSeq(CSeq::new(tokenizer.value))),
-                s if s.eq_ignore_ascii_case("Call-Id") => {
+                s if s.eq_ignore_ascii_case("Call-Id") || s.eq_ignore_ascii_case("i") => {
                     Ok(Header::CallId(CallId::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("Call-Info") => {
                     Ok(Header::CallInfo(CallInfo::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("Contact") => {
+                s if s.eq_ignore_ascii_case("Contact") || s.eq_ignore_ascii_case("m") => {
                     Ok(Header::Contact(Contact::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("Content-Disposition") => Ok(
                     Header::ContentDisposition(ContentDisposition::new(tokenizer.value)),
                 ),
-                s if s.eq_ignore_ascii_case("Content-Encoding") => Ok(Header::ContentEncoding(
-                    ContentEncoding::new(tokenizer.value),
-                )),
+                s if s.eq_ignore_ascii_case("Content-Encoding") || s.eq_ignore_ascii_case("e") => {
+                    Ok(Header::ContentEncoding(ContentEncoding::new(
+                        tokenizer.value,
+                    )))
+                }
                 s if s.eq_ignore_ascii_case("Content-Language") => Ok(Header::ContentLanguage(
                     ContentLanguage::new(tokenizer.value),
                 )),
-                s if s.eq_ignore_ascii_case("Content-Length") => {
+                s if s.eq_ignore_ascii_case("Content-Length") || s.eq_ignore_ascii_case("l") => {
                     Ok(Header::ContentLength(ContentLength::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("Content-Type") => {
+                s if s.eq_ignore_ascii_case("Content-Type") || s.eq_ignore_ascii_case("c") => {
                     Ok(Header::ContentType(ContentType::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("Date") => Ok(Header::Date(Date::new(tokenizer.value))),
                 s if s.eq_ignore_ascii_case("Error-Info") => {
                     Ok(Header::ErrorInfo(ErrorInfo::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("Event") => {
+                s if s.eq_ignore_ascii_case("Event") || s.eq_ignore_ascii_case("o") => {
                     Ok(Header::Event(Event::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("Expires") => {
                     Ok(Header::Expires(Expires::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("From") => Ok(Header::From(From::new(tokenizer.value))),
+                s if s.eq_ignore_ascii_case("From") || s.eq_ignore_ascii_case("f") => {
+                    Ok(Header::From(From::new(tokenizer.value)))
+                }
+                s if s.eq_ignore_ascii_case("Identity") || s.eq_ignore_ascii_case("y") => {
+                    Ok(Header::Identity(Identity::new(tokenizer.value)))
+                }
+                s if s.eq_ignore_ascii_case("Identity-Info") || s.eq_ignore_ascii_case("n") => {
+                    Ok(Header::IdentityInfo(IdentityInfo::new(tokenizer.value)))
+                }
                 s if s.eq_ignore_ascii_case("In-Reply-To") => {
                     Ok(Header::InReplyTo(InReplyTo::new(tokenizer.value)))
                 }
@@ -219,9 +343,25 @@ pub mod tokenizer {
                 s if s.eq_ignore_ascii_case("Record-Route") => {
                     Ok(Header::RecordRoute(RecordRoute::new(tokenizer.value)))
                 }
+                s if s.eq_ignore_ascii_case("Refer-To") || s.eq_ignore_ascii_case("r") => {
+                    Ok(Header::ReferTo(ReferTo::new(tokenizer.value)))
+                }
+                s if s.eq_ignore_ascii_case("Referred-By") || s.eq_ignore_ascii_case("b") => {
+                    Ok(Header::ReferredBy(ReferredBy::new(tokenizer.value)))
+                }
+                s if s.eq_ignore_ascii_case("Reject-Contact") || s.eq_ignore_ascii_case("j") => {
+                    Ok(Header::RejectContact(RejectContact::new(tokenizer.value)))
+                }
                 s if s.eq_ignore_ascii_case("Reply-To") => {
                     Ok(Header::ReplyTo(ReplyTo::new(tokenizer.value)))
                 }
+                s if s.eq_ignore_ascii_case("Request-Disposition")
+                    || s.eq_ignore_ascii_case("d") =>
+                {
+                    Ok(Header::RequestDisposition(RequestDisposition::new(
+                        tokenizer.value,
+                    )))
+                }
                 s if s.eq_ignore_ascii_case("Require") => {
                     Ok(Header::Require(Require::new(tokenizer.value)))
                 }
@@ -234,23 +374,33 @@ pub mod tokenizer {
                 s if s.eq_ignore_ascii_case("Server") => {
                     Ok(Header::Server(Server::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("Subject") => {
+                s if s.eq_ignore_ascii_case("Session-Expires") || s.eq_ignore_ascii_case("x") => {
+                    Ok(Header::SessionExpires(SessionExpires::new(tokenizer.value)))
+                }
+                s if s.eq_ignore_ascii_case("Subject") || s.eq_ignore_ascii_case("s") => {
                     Ok(Header::Subject(Subject::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("Supported") => {
+                s if s.eq_ignore_ascii_case("Subscription-State") => Ok(Header::SubscriptionState(
+                    SubscriptionState::new(tokenizer.value),
+                )),
+                s if s.eq_ignore_ascii_case("Supported") || s.eq_ignore_ascii_case("k") => {
                     Ok(Header::Supported(Supported::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("Timestamp") => {
                     Ok(Header::Timestamp(Timestamp::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("To") => Ok(Header::To(To::new(tokenizer.value))),
+                s if s.eq_ignore_ascii_case("To") || s.eq_ignore_ascii_case("t") => {
+                    Ok(Header::To(To::new(tokenizer.value)))
+                }
                 s if s.eq_ignore_ascii_case("Unsupported") => {
                     Ok(Header::Unsupported(Unsupported::new(tokenizer.value)))
                 }
                 s if s.eq_ignore_ascii_case("User-Agent") => {
                     Ok(Header::UserAgent(UserAgent::new(tokenizer.value)))
                 }
-                s if s.eq_ignore_ascii_case("Via") => Ok(Header::Via(Via::new(tokenizer.value))),
+                s if s.eq_ignore_ascii_case("Via") || s.eq_ignore_ascii_case("v") => {
+                    Ok(Header::Via(Via::new(tokenizer.value)))
+                }
                 s if s.eq_ignore_ascii_case("Warning") => {
                     Ok(Header::Warning(Warning::new(tokenizer.value)))
                 }
@@ -262,6 +412,32 @@ pub mod tokenizer {
         }
     }
 
+    //replaces any folded line (CRLF followed by SP/HTAB) along with its surrounding whitespace
+    //with a single SP, as rfc3261 (section 7.3.1) mandates
+    pub(crate) fn unfold(value: &str) -> std::borrow::Cow<'_, str> {
+        let is_wsp = |c: char| c == ' ' || c == '\t';
+
+        if !value.contains("\r\n") {
+            return value.into();
+        }
+
+        let lines = value.split("\r\n").collect::<Vec<_>>();
+        let last = lines.len() - 1;
+
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| match index {
+                0 => line.trim_end_matches(is_wsp),
+                index if index == last => line.trim_start_matches(is_wsp),
+                _ => line.trim_matches(is_wsp),
+            })
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .into()
+    }
+
     #[derive(Debug, PartialEq, Eq, Utf8Tokenizer)]
     pub struct Tokenizer<'a> {
         pub name: &'a [u8],
@@ -275,23 +451,95 @@ pub mod tokenizer {
                 branch::alt,
                 bytes::complete::{tag, take_until, take_while1},
                 character::complete::space0,
-                combinator::{map, rest},
+                combinator::{opt, recognize, rest},
+                multi::many0,
                 sequence::tuple,
             };
 
-            let (rem, (name, _, _, value)) = tuple((
+            //a value can span multiple lines, as long as each continuation line starts with
+            //whitespace (line folding), the tokenizer keeps the folded value as is
+            let (rem, (name, _, _, value, _)) = tuple((
                 take_while1(crate::parser_utils::is_token),
                 tag(":"),
                 space0,
-                alt((
-                    map(tuple((take_until("\r\n"), tag("\r\n"))), |(value, _)| value),
-                    rest,
-                )),
+                recognize(tuple((
+                    alt((take_until("\r\n"), rest)),
+                    many0(tuple((
+                        tag("\r\n"),
+                        take_while1(|c| c == b' ' || c == b'\t'),
+                        alt((take_until("\r\n"), rest)),
+                    ))),
+                ))),
+                opt(tag("\r\n")),
             ))(part)
             .map_err(|_: NomError<'a>| TokenizerError::from(("header", part)).into())?;
 
             Ok((rem, (name, value).into()))
         }
+
+        //tokenizes a single header line (along with any folded lines) as split by the caller,
+        //tolerating whitespace around the colon, the folded value is kept as is
+        pub fn tokenize_lenient(part: &'a [u8]) -> IResult<'a, Self> {
+            use crate::NomError;
+            use nom::{
+                bytes::complete::{tag, take_while, take_while1},
+                sequence::tuple,
+            };
+
+            let is_wsp = |c: u8| c == b' ' || c == b'\t';
+
+            let (value, (name, _, _, _)) = tuple((
+                take_while1(crate::parser_utils::is_token),
+                take_while(is_wsp),
+                tag(":"),
+                take_while(is_wsp),
+            ))(part)
+            .map_err(|_: NomError<'a>| TokenizerError::from(("header", part)).into())?;
+
+            let end = value
+                .iter()
+                .rposition(|c| !is_wsp(*c) && *c != b'\r' && *c != b'\n')
+                .map_or(0, |position| position + 1);
+
+            Ok((&[], (name, &value[..end]).into()))
+        }
+
+        //tokenizes the headers of a message up to the empty line, returning the body as the
+        //remaining input
+        pub(crate) fn tokenize_headers(
+            part: &'a [u8],
+        ) -> Result<(&'a [u8], Vec<Self>), TokenizeFailure<'a>> {
+            use crate::{parser_utils::is_empty_or_fail_with, NomError};
+            use nom::{
+                branch::alt,
+                bytes::complete::{tag, take_until},
+                multi::many0,
+                sequence::tuple,
+            };
+
+            let (body, (raw_headers, _)) = alt((
+                tuple((take_until("\r\n\r\n"), tag("\r\n\r\n"))),
+                tuple((take_until("\r\n"), tag("\r\n"))),
+            ))(part)
+            .map_err(|_: NomError<'a>| TokenizerError::from(("headers", part)).into())
+            .map_err(TokenizeFailure::of(part, Component::Headers))?;
+            let (rem, headers) = many0(Self::tokenize)(raw_headers)
+                .map_err(TokenizeFailure::of(raw_headers, Component::Headers))?;
+            is_empty_or_fail_with(rem, ("headers", rem))
+                .map_err(TokenizeFailure::trailing(rem, Component::Headers))?;
+
+            Ok((body, headers))
+        }
+
+        pub(crate) fn convert<L: Locate>(self, locator: &L) -> Result<Header, L::Error> {
+            let name = self.name;
+
+            locator.convert(
+                name,
+                || Component::Header(String::from_utf8_lossy(name).into()),
+                self,
+            )
+        }
     }
 
     impl<'a> std::convert::From<(&'a [u8], &'a [u8])> for Tokenizer<'a> {
@@ -0,0 +1,74 @@
+use super::{header, Header};
+use crate::Error;
+use std::{
+    borrow::Cow,
+    convert::{TryFrom, TryInto},
+};
+
+/// A header borrowed from the input it was parsed from, as found in a
+/// [SipMessageRef](crate::message::SipMessageRef). Neither the name nor the value are checked or
+/// copied until accessed. A value spanning multiple (folded) lines is kept as is in the
+/// [raw value](HeaderRef::raw_value), but unfolded (like in an owned [Header]) by
+/// [value](HeaderRef::value), which copies it only in that case.
+///
+/// A HeaderRef can be converted to an owned [Header] using the `TryFrom` conversion, which
+/// fails only if the header is not valid utf8.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HeaderRef<'a> {
+    name: &'a [u8],
+    value: &'a [u8],
+}
+
+impl<'a> HeaderRef<'a> {
+    pub fn name(&self) -> Result<&'a str, Error> {
+        Ok(std::str::from_utf8(self.name)?)
+    }
+
+    pub fn value(&self) -> Result<Cow<'a, str>, Error> {
+        Ok(header::tokenizer::unfold(std::str::from_utf8(self.value)?))
+    }
+
+    pub fn raw_name(&self) -> &'a [u8] {
+        self.name
+    }
+
+    pub fn raw_value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Whether the name of the header is the given one (or its compact form), case insensitive.
+    pub fn is(&self, name: &str, compact: Option<&str>) -> bool {
+        self.name.eq_ignore_ascii_case(name.as_bytes())
+            || compact
+                .map(|compact| self.name.eq_ignore_ascii_case(compact.as_bytes()))
+                .unwrap_or(false)
+    }
+}
+
+impl<'a> std::fmt::Display for HeaderRef<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            String::from_utf8_lossy(self.name),
+            String::from_utf8_lossy(self.value)
+        )
+    }
+}
+
+impl<'a> From<header::Tokenizer<'a>> for HeaderRef<'a> {
+    fn from(tokenizer: header::Tokenizer<'a>) -> Self {
+        Self {
+            name: tokenizer.name,
+            value: tokenizer.value,
+        }
+    }
+}
+
+impl<'a> TryFrom<HeaderRef<'a>> for Header {
+    type Error = Error;
+
+    fn try_from(header: HeaderRef<'a>) -> Result<Self, Self::Error> {
+        header::Tokenizer::from((header.name, header.value)).try_into()
+    }
+}
@@ -1,47 +1,301 @@
 pub mod auth;
+mod entry;
 pub mod header;
+pub mod header_ref;
 pub mod typed;
 pub mod untyped;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
 pub use header::Header;
+pub use header_ref::HeaderRef;
 pub use untyped::*;
 
-/// Simple NewType around `Vec<Header>` that gives many helpful methods when dealing with headers
-/// in [super::Request], [super::Response] and [super::SipMessage].
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub struct Headers(Vec<Header>);
+use std::{
+    collections::HashMap,
+    hash::{BuildHasherDefault, Hasher},
+    mem::Discriminant,
+};
+
+/// The headers of a [super::Request], [super::Response] and [super::SipMessage], kept in the order
+/// they were pushed (which is their order on the wire), along with an index by their kind, so that
+/// looking up a header doesn't need to go through all of them.
+///
+/// Headers are looked up by their name, case insensitive, where the compact form of a name maps to
+/// the same header (like `v` and `Via`), while any header that is not defined in Rsip (the
+/// `Other` variant) is looked up by its own name:
+/// ```
+/// use rsip::headers::{CallId, UntypedHeader};
+///
+/// let mut headers: rsip::Headers = Default::default();
+/// headers.push(CallId::new("1j9FpLxk3uxtm8tn").into());
+/// headers.push(rsip::Header::Other("X-Forward".into(), "202.45.213.14".into()));
+///
+/// assert_eq!(headers.get("i"), Some(&CallId::new("1j9FpLxk3uxtm8tn").into()));
+/// assert!(headers.get("x-forward").is_some());
+/// ```
+#[derive(Clone, Default)]
+pub struct Headers {
+    headers: Vec<Header>,
+    index: HashMap<Key, Vec<usize>, BuildHasherDefault<KeyHasher>>,
+    //set whenever a `&mut Header` is handed out, since its kind could change, in which case
+    //lookups go through all headers until the index is rebuilt by the next modification
+    stale: bool,
+}
+
+//the kind of a header, which for the `Other` variant is its lowercase name
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub(crate) enum Key {
+    Kind(Discriminant<Header>),
+    Other(String),
+}
+
+//a multiply-rotate hasher (like the one of rustc), since the keys are few and short, and the
+//default SipHash would take most of the time of a lookup
+#[derive(Default)]
+pub(crate) struct KeyHasher(u64);
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|byte| self.write_u64(*byte as u64));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = (self.0.rotate_left(5) ^ i).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64)
+    }
+}
+
+impl Key {
+    pub(crate) fn of(header: &Header) -> Self {
+        match header {
+            Header::Other(name, _) => Self::Other(name.to_ascii_lowercase()),
+            header => Self::Kind(std::mem::discriminant(header)),
+        }
+    }
+
+    pub(crate) fn named(name: &str) -> Self {
+        use std::convert::TryInto;
+
+        match header::Tokenizer::from((name.as_bytes(), &b""[..])).try_into() {
+            Ok(header) => Self::of(&header),
+            Err(_) => Self::Other(name.to_ascii_lowercase()),
+        }
+    }
+}
 
 impl Headers {
     pub fn push(&mut self, h: Header) {
-        self.0.push(h)
+        self.reindex_if_stale();
+        self.index
+            .entry(Key::of(&h))
+            .or_default()
+            .push(self.headers.len());
+        self.headers.push(h);
     }
 
+    /// Same as [push](Headers::push).
+    pub fn append(&mut self, h: Header) {
+        self.push(h)
+    }
+
+    /// Removes any header of the same kind and pushes the given header at the end.
     pub fn unique_push(&mut self, h: Header) {
-        self.0
-            .retain(|s| std::mem::discriminant(s) != std::mem::discriminant(&h));
+        self.remove_key(&Key::of(&h));
         self.push(h);
     }
 
+    /// Replaces the first header of the same kind with the given one, keeping its position, while
+    /// any other header of that kind is removed. The replaced headers are returned, in order. If
+    /// there is no header of that kind, the header is pushed at the end.
+    pub fn insert(&mut self, h: Header) -> Vec<Header> {
+        self.reindex_if_stale();
+
+        let positions = self.index.get(&Key::of(&h)).cloned().unwrap_or_default();
+        let first = match positions.first() {
+            Some(first) => *first,
+            None => {
+                self.push(h);
+                return vec![];
+            }
+        };
+
+        let mut replaced = positions[1..]
+            .iter()
+            .rev()
+            .map(|position| self.headers.remove(*position))
+            .collect::<Vec<_>>();
+        replaced.push(std::mem::replace(&mut self.headers[first], h));
+        self.reindex();
+
+        replaced.into_iter().rev().collect()
+    }
+
+    /// Returns the first header with the given name.
+    pub fn get(&self, name: &str) -> Option<&Header> {
+        self.lookup(&Key::named(name)).next()
+    }
+
+    /// Returns all headers with the given name, in order.
+    pub fn get_all(&self, name: &str) -> Vec<&Header> {
+        self.lookup(&Key::named(name)).collect()
+    }
+
+    /// Removes all headers with the given name, returning them in order.
+    pub fn remove(&mut self, name: &str) -> Vec<Header> {
+        self.remove_key(&Key::named(name))
+    }
+
+    /// Gets the entry of the headers with the given name, for in-place manipulation.
+    pub fn entry(&mut self, name: &str) -> Entry<'_> {
+        self.reindex_if_stale();
+
+        let key = Key::named(name);
+        match self.index.contains_key(&key) {
+            true => Entry::Occupied(OccupiedEntry { headers: self, key }),
+            false => Entry::Vacant(VacantEntry { headers: self }),
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Header> {
-        self.0.iter()
+        self.headers.iter()
     }
 
     pub fn extend(&mut self, i: Vec<Header>) {
-        self.0.extend(i)
+        i.into_iter().for_each(|header| self.push(header))
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Header> {
-        self.0.iter_mut()
+        self.stale = true;
+        self.headers.iter_mut()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.headers.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.headers.len()
     }
 
     pub fn retain<F>(&mut self, f: F)
     where
         F: FnMut(&Header) -> bool,
     {
-        self.0.retain(f)
+        self.headers.retain(f);
+        self.reindex();
+    }
+
+    pub(crate) fn lookup<'a>(&'a self, key: &Key) -> impl Iterator<Item = &'a Header> + 'a {
+        let positions = match self.stale {
+            true => &[][..],
+            false => self.index.get(key).map(Vec::as_slice).unwrap_or_default(),
+        };
+        let scan = match self.stale {
+            true => Some(key.clone()),
+            false => None,
+        };
+
+        positions
+            .iter()
+            .map(move |position| &self.headers[*position])
+            .chain(scan.into_iter().flat_map(move |key| {
+                self.headers
+                    .iter()
+                    .filter(move |header| Key::of(header) == key)
+            }))
+    }
+
+    //the caller is not expected to change the kind of the header
+    pub(crate) fn lookup_mut(&mut self, key: &Key) -> Option<&mut Header> {
+        self.reindex_if_stale();
+
+        let first = *self.index.get(key)?.first()?;
+        self.headers.get_mut(first)
+    }
+
+    //same as `lookup_mut`, for callers that could change the kind of the header
+    pub(crate) fn lookup_mut_untracked(&mut self, key: &Key) -> Option<&mut Header> {
+        self.reindex_if_stale();
+
+        let first = *self.index.get(key)?.first()?;
+        self.stale = true;
+        self.headers.get_mut(first)
+    }
+
+    pub(crate) fn remove_key(&mut self, key: &Key) -> Vec<Header> {
+        self.reindex_if_stale();
+
+        let positions = match self.index.remove(key) {
+            Some(positions) => positions,
+            None => return vec![],
+        };
+        let removed = positions
+            .into_iter()
+            .rev()
+            .map(|position| self.headers.remove(position))
+            .collect::<Vec<_>>();
+        self.reindex();
+
+        removed.into_iter().rev().collect()
+    }
+
+    fn reindex_if_stale(&mut self) {
+        if self.stale {
+            self.reindex();
+        }
+    }
+
+    fn reindex(&mut self) {
+        self.index.clear();
+        for (position, header) in self.headers.iter().enumerate() {
+            self.index
+                .entry(Key::of(header))
+                .or_default()
+                .push(position);
+        }
+        self.stale = false;
+    }
+}
+
+impl PartialEq for Headers {
+    fn eq(&self, other: &Self) -> bool {
+        self.headers == other.headers
+    }
+}
+
+impl Eq for Headers {}
+
+impl std::fmt::Debug for Headers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Headers").field(&self.headers).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Headers {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("Headers", &self.headers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Headers {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Headers")]
+        struct Raw(Vec<Header>);
+
+        Raw::deserialize(deserializer).map(|raw| raw.0.into())
     }
 }
 
@@ -50,41 +304,40 @@ impl IntoIterator for Headers {
     type Item = Header;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.headers.into_iter()
     }
 }
 
 impl std::convert::From<Header> for Headers {
     fn from(header: Header) -> Self {
-        Self(vec![header])
+        vec![header].into()
     }
 }
 
 impl std::convert::From<Vec<Header>> for Headers {
     fn from(headers: Vec<Header>) -> Self {
-        Self(headers)
+        let mut headers = Self {
+            headers,
+            ..Default::default()
+        };
+        headers.reindex();
+        headers
     }
 }
 
 impl std::convert::From<Headers> for Vec<Header> {
     fn from(from: Headers) -> Self {
-        from.0
+        from.headers
     }
 }
 
+/// Using the alternate flag (`{:#}`) prints each header using its compact form (like `v` for
+/// `Via` or `i` for `Call-ID`), if the header has one. Useful for keeping UDP datagrams small.
 impl std::fmt::Display for Headers {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.is_empty() {
-            write!(f, "")
-        } else {
-            write!(
-                f,
-                "{}\r\n",
-                self.iter()
-                    .map(|s| s.to_string())
-                    .collect::<Vec<_>>()
-                    .join("\r\n")
-            )
-        }
+        self.iter().try_for_each(|header| match f.alternate() {
+            true => write!(f, "{:#}\r\n", header),
+            false => write!(f, "{}\r\n", header),
+        })
     }
 }
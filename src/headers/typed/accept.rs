@@ -8,6 +8,7 @@ use std::convert::TryFrom;
 
 /// The `Accept` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Accept(pub Vec<MediaType>);
 
 impl From<Vec<MediaType>> for Accept {
@@ -7,6 +7,7 @@ pub use super::tokenizers::TokenListTokenizer as Tokenizer;
 
 /// The `Allow` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Allow(pub Vec<Method>);
 
 impl From<Vec<Method>> for Allow {
@@ -7,6 +7,7 @@ use std::convert::TryFrom;
 
 /// The `Authentication-Info` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuthenticationInfo {
     pub nextnonce: String,
     pub qop: Option<AuthQop>,
@@ -61,18 +62,23 @@ fn find_qop<'a>(params: &[(&'a str, &'a str)]) -> Result<Option<AuthQop>, Error>
                 .ok_or_else(|| Error::InvalidParam("Found qop, but missing cnonce".into()))?
                 .into(),
             nc: find_param(params, "nc")
-                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))?
-                .parse::<u8>()?,
+                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))
+                .and_then(parse_nc)?,
         }),
         Some(qop) if qop.eq_ignore_ascii_case("auth-int") => Some(AuthQop::AuthInt {
             cnonce: find_param(params, "cnonce")
                 .ok_or_else(|| Error::InvalidParam("Found qop, but missing cnonce".into()))?
                 .into(),
             nc: find_param(params, "nc")
-                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))?
-                .parse::<u8>()?,
+                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))
+                .and_then(parse_nc)?,
         }),
         Some(qop) => return Err(Error::InvalidParam(format!("Found unknown qop: {}", qop))),
         None => None,
     })
 }
+
+//the nonce count is 8 hex digits
+fn parse_nc(nc: &str) -> Result<u32, Error> {
+    Ok(u32::from_str_radix(nc, 16)?)
+}
@@ -10,7 +10,15 @@ use rsip_derives::TypedHeader;
 use std::convert::{TryFrom, TryInto};
 
 /// The `Authorization` header in its [typed](super) form.
+///
+/// The `username` is always the actual username (or its hash when `userhash` is true), even if it
+/// was given through the `username*` parameter of
+/// [RFC7616](https://datatracker.ietf.org/doc/html/rfc7616#section-3.4.4), using the
+/// [RFC5987](https://datatracker.ietf.org/doc/html/rfc5987) encoding. When displayed, the
+/// `username*` parameter is used instead of `username` only if the username can't be a quoted
+/// string, like when it has non-ASCII characters.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Authorization {
     pub scheme: auth::Scheme,
     pub username: String,
@@ -19,18 +27,10 @@ pub struct Authorization {
     pub uri: Uri,
     pub response: String,
     pub algorithm: Option<Algorithm>,
-    //TODO: support username* here in combination with userhash
-    //TODO: this qop is not optional in rfc7616
-    //also the cnonce and nc optional depends on qop
-    //we should use an enum Qop with cnonce & nc fields instead
-    //pub cnonce: Option<String>,
     pub opaque: Option<String>,
-    //TODO: support multiple Qop
+    //TODO: this qop is not optional in rfc7616
     pub qop: Option<AuthQop>,
-    //TODO: this needs to be a u8
-    //pub nc: Option<String>,
-    //TODO: enable this in combination with username*
-    //pub userhash: Option<bool>,
+    pub userhash: Option<bool>,
 }
 
 impl<'a> TryFrom<Tokenizer<'a>> for Authorization {
@@ -39,9 +39,12 @@ impl<'a> TryFrom<Tokenizer<'a>> for Authorization {
     fn try_from(tokenizer: Tokenizer) -> Result<Self, Self::Error> {
         Ok(Authorization {
             scheme: tokenizer.scheme.try_into()?,
-            username: find_param(&tokenizer.params, "username")
-                .ok_or_else(|| Error::InvalidParam("missing username".into()))?
-                .into(),
+            username: match find_param(&tokenizer.params, "username*") {
+                Some(username) => decode_ext_value(username)?,
+                None => find_param(&tokenizer.params, "username")
+                    .ok_or_else(|| Error::InvalidParam("missing username".into()))?
+                    .into(),
+            },
             realm: find_param(&tokenizer.params, "realm")
                 .ok_or_else(|| Error::InvalidParam("missing realm".into()))?
                 .into(),
@@ -59,16 +62,28 @@ impl<'a> TryFrom<Tokenizer<'a>> for Authorization {
                 .transpose()?,
             opaque: find_param(&tokenizer.params, "opaque").map(Into::into),
             qop: find_qop(&tokenizer.params)?,
+            userhash: find_param(&tokenizer.params, "userhash")
+                .map(|userhash| userhash.eq_ignore_ascii_case("true")),
         })
     }
 }
 
 impl std::fmt::Display for Authorization {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match is_quotable(&self.username) {
+            true => write!(f, "{} username=\"{}\"", self.scheme, self.username)?,
+            false => write!(
+                f,
+                "{} username*={}",
+                self.scheme,
+                encode_ext_value(&self.username)
+            )?,
+        }
+
         write!(
             f,
-            "{} username=\"{}\", realm=\"{}\", nonce=\"{}\", uri=\"{}\", response=\"{}\"",
-            self.scheme, self.username, self.realm, self.nonce, self.uri, self.response
+            ", realm=\"{}\", nonce=\"{}\", uri=\"{}\", response=\"{}\"",
+            self.realm, self.nonce, self.uri, self.response
         )?;
 
         if let Some(algorithm) = &self.algorithm {
@@ -83,6 +98,10 @@ impl std::fmt::Display for Authorization {
             write!(f, ", {}", qop)?;
         }
 
+        if let Some(userhash) = &self.userhash {
+            write!(f, ", userhash={}", userhash)?;
+        }
+
         Ok(())
     }
 }
@@ -104,18 +123,88 @@ fn find_qop<'a>(params: &[(&'a str, &'a str)]) -> Result<Option<AuthQop>, Error>
                 .ok_or_else(|| Error::InvalidParam("Found qop, but missing cnonce".into()))?
                 .into(),
             nc: find_param(params, "nc")
-                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))?
-                .parse::<u8>()?,
+                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))
+                .and_then(parse_nc)?,
         }),
         Some(qop) if qop.eq_ignore_ascii_case("auth-int") => Some(AuthQop::AuthInt {
             cnonce: find_param(params, "cnonce")
                 .ok_or_else(|| Error::InvalidParam("Found qop, but missing cnonce".into()))?
                 .into(),
             nc: find_param(params, "nc")
-                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))?
-                .parse::<u8>()?,
+                .ok_or_else(|| Error::InvalidParam("Found qop, but missing nc".into()))
+                .and_then(parse_nc)?,
         }),
         Some(qop) => return Err(Error::InvalidParam(format!("Found unknown qop: {}", qop))),
         None => None,
     })
 }
+
+//the nonce count is 8 hex digits
+fn parse_nc(nc: &str) -> Result<u32, Error> {
+    Ok(u32::from_str_radix(nc, 16)?)
+}
+
+//whether the username can be displayed as a quoted string, otherwise username* is used
+fn is_quotable(username: &str) -> bool {
+    username
+        .chars()
+        .all(|c| c.is_ascii() && !c.is_ascii_control() && c != '"' && c != '\\')
+}
+
+//RFC5987 ext-value, always encoded as UTF-8 without a language
+fn encode_ext_value(value: &str) -> String {
+    let encoded = value
+        .bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z'
+            | b'A'..=b'Z'
+            | b'0'..=b'9'
+            | b'!'
+            | b'#'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b'-'
+            | b'.'
+            | b'^'
+            | b'_'
+            | b'`'
+            | b'|'
+            | b'~' => (byte as char).to_string(),
+            byte => format!("%{:02X}", byte),
+        })
+        .collect::<String>();
+
+    format!("UTF-8''{}", encoded)
+}
+
+fn decode_ext_value(value: &str) -> Result<String, Error> {
+    let invalid = || Error::InvalidParam(format!("invalid username*: {}", value));
+
+    let mut parts = value.splitn(3, '\'');
+    let (charset, encoded) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(charset), Some(_language), Some(encoded)) => (charset, encoded),
+        _ => return Err(invalid()),
+    };
+    if !charset.eq_ignore_ascii_case("utf-8") {
+        return Err(invalid());
+    }
+
+    let mut bytes = vec![];
+    let mut encoded = encoded.bytes();
+    while let Some(byte) = encoded.next() {
+        match byte {
+            b'%' => {
+                let hex = [
+                    encoded.next().ok_or_else(invalid)?,
+                    encoded.next().ok_or_else(invalid)?,
+                ];
+                let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
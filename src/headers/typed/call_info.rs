@@ -7,6 +7,7 @@ use std::convert::TryFrom;
 
 /// The `Call-Info` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallInfo(pub UriWithParamsList);
 
 impl CallInfo {
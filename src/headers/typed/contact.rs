@@ -10,6 +10,7 @@ use std::convert::{TryFrom, TryInto};
 
 /// The `Contact` header in its [typed](super) form.
 #[derive(TypedHeader, UriAndParamsHelpers, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Contact {
     pub display_name: Option<String>,
     pub uri: Uri,
@@ -6,6 +6,7 @@ use std::convert::TryFrom;
 
 /// The `Content-Disposition` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContentDisposition {
     pub display_type: DisplayType,
     pub display_params: Vec<DisplayTypeParam>,
@@ -18,6 +19,7 @@ impl ContentDisposition {
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayType {
     Render,
     Session,
@@ -27,6 +29,7 @@ pub enum DisplayType {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DisplayTypeParam(pub String, pub String);
 
 impl<S, T> From<(S, T)> for DisplayTypeParam
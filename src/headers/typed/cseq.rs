@@ -7,6 +7,7 @@ use std::convert::TryFrom;
 
 /// The `CSeq` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CSeq {
     pub seq: u32,
     pub method: Method,
@@ -8,6 +8,7 @@ use std::convert::{TryFrom, TryInto};
 
 /// The `From` header in its [typed](super) form.
 #[derive(TypedHeader, UriAndParamsHelpers, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct From {
     pub display_name: Option<String>,
     pub uri: Uri,
@@ -6,6 +6,7 @@ use std::convert::TryFrom;
 
 /// The `In-Reply-To` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InReplyTo(pub Vec<String>);
 
 impl<'a> TryFrom<Tokenizer<'a>> for InReplyTo {
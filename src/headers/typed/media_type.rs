@@ -4,12 +4,14 @@ pub use super::tokenizers::NameParamsTokenizer as Tokenizer;
 use std::convert::TryFrom;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MediaType {
     Sdp(Vec<MediaTypeParam>),
     Other(String, Vec<MediaTypeParam>),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaTypeParam(pub String, pub String);
 
 impl<S, T> From<(S, T)> for MediaTypeParam
@@ -6,6 +6,7 @@ use std::convert::TryFrom;
 
 /// The `Priority` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Priority {
     NonUrgent,
     Normal,
@@ -6,6 +6,7 @@ use std::convert::TryFrom;
 
 /// The `Proxy-Authenticate` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProxyAuthenticate(pub super::WwwAuthenticate);
 
 impl<'a> TryFrom<Tokenizer<'a>> for ProxyAuthenticate {
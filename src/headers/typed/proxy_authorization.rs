@@ -6,6 +6,7 @@ use std::convert::TryFrom;
 
 /// The `Proxy-Authorization` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProxyAuthorization(pub super::Authorization);
 
 impl<'a> TryFrom<Tokenizer<'a>> for ProxyAuthorization {
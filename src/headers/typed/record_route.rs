@@ -7,6 +7,7 @@ use std::convert::TryFrom;
 
 /// The `Record-Route` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RecordRoute(pub UriWithParamsList);
 
 impl RecordRoute {
@@ -15,9 +15,9 @@ impl<'a> Tokenize<'a> for AuthTokenizer<'a> {
         use crate::NomStrError;
         use nom::{
             branch::alt,
-            bytes::complete::{tag, take_until},
-            character::complete::space0,
-            combinator::{map, opt, rest},
+            bytes::complete::{escaped, is_not, tag, take_until},
+            character::complete::{anychar, space0},
+            combinator::{map, opt, recognize, rest},
             multi::many1,
             sequence::{delimited, tuple},
         };
@@ -28,7 +28,11 @@ impl<'a> Tokenize<'a> for AuthTokenizer<'a> {
                 take_until("="),
                 tag("="),
                 alt((
-                    delimited(tag("\""), take_until("\""), tag("\"")),
+                    delimited(
+                        tag("\""),
+                        recognize(opt(escaped(is_not("\\\""), '\\', anychar))),
+                        tag("\""),
+                    ),
                     take_until(","),
                     take_until(" "),
                     rest,
@@ -49,3 +53,43 @@ impl<'a> Tokenize<'a> for AuthTokenizer<'a> {
         Ok(Self { scheme, params })
     }
 }
+
+impl<'a> AuthTokenizer<'a> {
+    /// Splits a `WWW-Authenticate` (or `Proxy-Authenticate`) value into its challenges, where a
+    /// new challenge starts after a comma (outside of a quoted string, which may contain escaped
+    /// quotes) with a scheme, a token not followed by `=`.
+    pub fn split_challenges(part: &'a str) -> Vec<&'a str> {
+        let mut challenges = vec![];
+        let mut start = 0;
+        let mut quoted = false;
+        let mut escaped = false;
+
+        for (index, c) in part.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if quoted => escaped = true,
+                '"' => quoted = !quoted,
+                ',' if !quoted && starts_with_scheme(&part[index + 1..]) => {
+                    challenges.push(part[start..index].trim());
+                    start = index + 1;
+                }
+                _ => (),
+            }
+        }
+        challenges.push(part[start..].trim());
+
+        challenges
+            .into_iter()
+            .filter(|challenge| !challenge.is_empty())
+            .collect()
+    }
+}
+
+fn starts_with_scheme(part: &str) -> bool {
+    let part = part.trim_start();
+    let token = part
+        .find(|c: char| c.is_whitespace() || c == '=' || c == ',')
+        .unwrap_or(part.len());
+
+    token > 0 && !part[token..].trim_start().starts_with('=')
+}
@@ -16,6 +16,7 @@ use std::{
 
 /// The `Via` header in its [typed](super) form.
 #[derive(TypedHeader, UriAndParamsHelpers, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Via {
     pub version: Version,
     pub transport: Transport,
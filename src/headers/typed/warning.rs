@@ -7,6 +7,7 @@ use std::convert::{TryFrom, TryInto};
 
 /// The `Record-Warning` header in its [typed](super) form.
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Warning {
     pub code: u16,
     pub uri: Uri,
@@ -6,8 +6,11 @@ use crate::{headers::auth, Error};
 use rsip_derives::TypedHeader;
 use std::convert::{TryFrom, TryInto};
 
-/// The `WwwAuthenticate` header in its [typed](super) form.
+/// The `WwwAuthenticate` header in its [typed](super) form, holding a single challenge. Since a
+/// header can have more than one challenge, the ones of a [Response](crate::Response) are better
+/// accessed through [Response::www_authenticate_challenges](crate::Response::www_authenticate_challenges).
 #[derive(TypedHeader, Eq, PartialEq, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WwwAuthenticate {
     pub scheme: auth::Scheme,
     pub realm: String,
@@ -16,10 +19,9 @@ pub struct WwwAuthenticate {
     pub opaque: Option<String>,
     pub stale: Option<String>,
     pub algorithm: Option<Algorithm>,
-    //TODO: support multiple Qop
-    pub qop: Option<Qop>,
+    pub qop: Vec<Qop>,
     pub charset: Option<String>,
-    //pub userhash: Option<bool>,
+    pub userhash: Option<bool>,
 }
 
 impl<'a> TryFrom<Tokenizer<'a>> for WwwAuthenticate {
@@ -40,10 +42,17 @@ impl<'a> TryFrom<Tokenizer<'a>> for WwwAuthenticate {
             algorithm: find_param(&tokenizer.params, "algorithm")
                 .map(TryInto::try_into)
                 .transpose()?,
+            //unrecognized qop options are ignored, as RFC2617 requires
             qop: find_param(&tokenizer.params, "qop")
-                .map(TryInto::try_into)
-                .transpose()?,
+                .map(|qop| {
+                    qop.split(',')
+                        .filter_map(|qop| Qop::try_from(qop.trim()).ok())
+                        .collect()
+                })
+                .unwrap_or_default(),
             charset: find_param(&tokenizer.params, "charset").map(Into::into),
+            userhash: find_param(&tokenizer.params, "userhash")
+                .map(|userhash| userhash.eq_ignore_ascii_case("true")),
         })
     }
 }
@@ -69,7 +78,13 @@ impl std::fmt::Display for WwwAuthenticate {
             write!(f, ", algorithm={}", algorithm)?;
         }
 
-        if let Some(qop) = &self.qop {
+        if !self.qop.is_empty() {
+            let qop = self
+                .qop
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
             write!(f, ", qop=\"{}\"", qop)?;
         }
 
@@ -77,6 +92,10 @@ impl std::fmt::Display for WwwAuthenticate {
             write!(f, ", charset={}", charset)?;
         }
 
+        if let Some(userhash) = &self.userhash {
+            write!(f, ", userhash={}", userhash)?;
+        }
+
         Ok(())
     }
 }
@@ -2,4 +2,5 @@ use rsip_derives::UntypedHeader;
 
 /// The `Accept-Language` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AcceptLanguage(String);
@@ -2,6 +2,7 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `Allow` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Allow(String);
 
 impl Default for Allow {
@@ -2,4 +2,5 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `Authentication-Info` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuthenticationInfo(String);
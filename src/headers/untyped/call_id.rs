@@ -2,8 +2,9 @@ use rsip_derives::UntypedHeader;
 use uuid::Uuid;
 
 /// The `Call-ID` header in its [untyped](super) form.
-#[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
-#[header(display_name = "Call-ID")]
+#[derive(UntypedHeader, Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(display_name = "Call-ID", compact_name = "i")]
 pub struct CallId(String);
 
 impl Default for CallId {
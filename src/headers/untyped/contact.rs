@@ -10,6 +10,8 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `Contact` header in its [untyped](super) form.
 #[derive(ToTypedHeader, UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "m")]
 pub struct Contact(String);
 
 impl Contact {
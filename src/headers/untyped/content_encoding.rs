@@ -2,4 +2,6 @@ use rsip_derives::UntypedHeader;
 
 /// The `Content-Encoding` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "e")]
 pub struct ContentEncoding(String);
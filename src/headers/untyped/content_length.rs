@@ -2,6 +2,8 @@ use rsip_derives::UntypedHeader;
 
 /// The `Content-Length` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "l")]
 pub struct ContentLength(String);
 
 impl Default for ContentLength {
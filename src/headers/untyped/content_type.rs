@@ -2,4 +2,6 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `Content-Type` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "c")]
 pub struct ContentType(String);
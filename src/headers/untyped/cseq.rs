@@ -3,6 +3,7 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `CSeq` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[header(display_name = "CSeq")]
 pub struct CSeq(String);
 
@@ -7,6 +7,8 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `From` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "f")]
 pub struct From(String);
 
 impl From {
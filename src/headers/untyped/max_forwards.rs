@@ -3,6 +3,7 @@ use rsip_derives::UntypedHeader;
 
 /// The `Max-Forwards` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxForwards(String);
 
 impl Default for MaxForwards {
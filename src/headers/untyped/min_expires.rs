@@ -3,6 +3,7 @@ use rsip_derives::UntypedHeader;
 
 /// The `Min-Expires` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinExpires(String);
 
 impl MinExpires {
@@ -12,10 +12,12 @@
 use crate::Header;
 
 pub mod accept;
+pub mod accept_contact;
 pub mod accept_encoding;
 pub mod accept_language;
 pub mod alert_info;
 pub mod allow;
+pub mod allow_events;
 pub mod authentication_info;
 pub mod authorization;
 pub mod call_id;
@@ -32,6 +34,8 @@ pub mod error_info;
 pub mod event;
 pub mod expires;
 pub mod from;
+pub mod identity;
+pub mod identity_info;
 pub mod in_reply_to;
 pub mod max_forwards;
 pub mod mime_version;
@@ -42,11 +46,16 @@ pub mod proxy_authenticate;
 pub mod proxy_authorization;
 pub mod proxy_require;
 pub mod record_route;
+pub mod refer_to;
+pub mod referred_by;
+pub mod reject_contact;
 pub mod reply_to;
+pub mod request_disposition;
 pub mod require;
 pub mod retry_after;
 pub mod route;
 pub mod server;
+pub mod session_expires;
 pub mod subject;
 pub mod subscription_state;
 pub mod supported;
@@ -59,10 +68,12 @@ pub mod warning;
 pub mod www_authenticate;
 
 pub use accept::Accept;
+pub use accept_contact::AcceptContact;
 pub use accept_encoding::AcceptEncoding;
 pub use accept_language::AcceptLanguage;
 pub use alert_info::AlertInfo;
 pub use allow::Allow;
+pub use allow_events::AllowEvents;
 pub use authentication_info::AuthenticationInfo;
 pub use authorization::Authorization;
 pub use call_id::CallId;
@@ -79,6 +90,8 @@ pub use error_info::ErrorInfo;
 pub use event::Event;
 pub use expires::Expires;
 pub use from::From;
+pub use identity::Identity;
+pub use identity_info::IdentityInfo;
 pub use in_reply_to::InReplyTo;
 pub use max_forwards::MaxForwards;
 pub use mime_version::MimeVersion;
@@ -89,11 +102,16 @@ pub use proxy_authenticate::ProxyAuthenticate;
 pub use proxy_authorization::ProxyAuthorization;
 pub use proxy_require::ProxyRequire;
 pub use record_route::RecordRoute;
+pub use refer_to::ReferTo;
+pub use referred_by::ReferredBy;
+pub use reject_contact::RejectContact;
 pub use reply_to::ReplyTo;
+pub use request_disposition::RequestDisposition;
 pub use require::Require;
 pub use retry_after::RetryAfter;
 pub use route::Route;
 pub use server::Server;
+pub use session_expires::SessionExpires;
 pub use subject::Subject;
 pub use subscription_state::SubscriptionState;
 pub use supported::Supported;
@@ -0,0 +1,7 @@
+use rsip_derives::UntypedHeader;
+
+/// The `Referred-By` header in its [untyped](super) form.
+#[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "b")]
+pub struct ReferredBy(String);
@@ -0,0 +1,7 @@
+use rsip_derives::UntypedHeader;
+
+/// The `Reject-Contact` header in its [untyped](super) form.
+#[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "j")]
+pub struct RejectContact(String);
@@ -0,0 +1,7 @@
+use rsip_derives::UntypedHeader;
+
+/// The `Request-Disposition` header in its [untyped](super) form.
+#[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "d")]
+pub struct RequestDisposition(String);
@@ -2,6 +2,7 @@ use rsip_derives::UntypedHeader;
 
 /// The `Server` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Server(String);
 
 impl Default for Server {
@@ -2,6 +2,7 @@ use rsip_derives::UntypedHeader;
 
 /// The `User-Agent` header in its [untyped](super) form.
 #[derive(UntypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserAgent(String);
 
 impl Default for UserAgent {
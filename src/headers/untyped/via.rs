@@ -10,6 +10,8 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `Via` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[header(compact_name = "v")]
 pub struct Via(String);
 
 impl Via {
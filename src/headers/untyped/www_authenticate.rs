@@ -2,5 +2,6 @@ use rsip_derives::{ToTypedHeader, UntypedHeader};
 
 /// The `WWW-Authenticate` header in its [untyped](super) form.
 #[derive(UntypedHeader, ToTypedHeader, Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[header(display_name = "WWW-Authenticate")]
 pub struct WwwAuthenticate(String);
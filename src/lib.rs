@@ -166,13 +166,12 @@
 //! ```
 //! let x_fs_sending_message = rsip::Header::Other("X-FS-Sending-Message".into(), "f9c4adc8-9c2a-47d5-a7f1-63d20784685e".into());
 //! ```
-//! ## Headers: a vec of headers
-//! [Headers](Headers) is a newtype around `Vec<Header>`, but it's there to give you better safety along
-//! with some helpful methods. In SIP, many headers are allowed to appear more than once time, so
-//! if we want to provide something else, maybe we should go with something similar to what the
-//! http crate does (a multimap HashMap to take advantage of the characteristics of HTTP headers).
-//! But this shouldn't worry you as the external API will remain the same even if the internal
-//! implementation is moved from a `Vec<Header>` to a multimap HashMap.
+//! ## Headers: a multimap of headers
+//! [Headers](Headers) keeps the headers in their order (which matters in SIP, for instance for
+//! `Via` and `Route` headers), along with an index by their kind, similar to what the http crate
+//! does, since in SIP many headers are allowed to appear more than once. Apart from iterating
+//! over them, headers can be looked up by their name through `get` and `get_all`, while they can be
+//! modified through `insert`, `append`, `remove` and `entry`.
 //!
 //! In order to push some headers in the [Headers](Headers) you can simple do:
 //! ```
@@ -264,8 +263,10 @@ mod error;
 pub mod headers;
 pub mod message;
 pub mod services;
+#[cfg(feature = "transaction")]
+pub mod transaction;
 
-pub use error::{Error, TokenizerError};
+pub use error::{Component, Error, ErrorKind, PositionedError, TokenizerError, UriPart};
 
 pub use headers::{Header, Headers};
 pub use message::{Request, Response, SipMessage};
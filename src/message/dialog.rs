@@ -0,0 +1,295 @@
+use crate::{
+    common::uri::{
+        param::{Branch, Tag},
+        Param, Scheme, UriWithParams, UriWithParamsList,
+    },
+    headers::{typed, untyped::ToTypedHeader, CallId, Headers, MaxForwards},
+    message::HeadersExt,
+    Error, Method, Request, Response, StatusCodeKind, Uri, Version,
+};
+
+/// Identifier of a dialog, as defined in
+/// [RFC3261 section 12](https://datatracker.ietf.org/doc/html/rfc3261#section-12), meant to be
+/// used as a `HashMap` key when matching messages to dialogs.
+///
+/// The same dialog has a different id on each side, since the local tag of the one side is the
+/// remote tag of the other:
+/// * [uac](DialogId::uac) computes the id on the side that sent the request, where the `From` tag
+///   is the local tag (outgoing requests, incoming responses).
+/// * [uas](DialogId::uas) computes the id on the side that received the request, where the `To`
+///   tag is the local tag (incoming requests, outgoing responses).
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogId {
+    pub call_id: CallId,
+    pub local_tag: Tag,
+    pub remote_tag: Tag,
+}
+
+impl DialogId {
+    pub fn uac(message: &impl HeadersExt) -> Result<Self, Error> {
+        Ok(Self {
+            call_id: message.call_id_header()?.clone(),
+            local_tag: from_tag(message)?,
+            remote_tag: to_tag(message)?,
+        })
+    }
+
+    pub fn uas(message: &impl HeadersExt) -> Result<Self, Error> {
+        Ok(Self {
+            call_id: message.call_id_header()?.clone(),
+            local_tag: to_tag(message)?,
+            remote_tag: from_tag(message)?,
+        })
+    }
+}
+
+/// The state of a [Dialog]: `Early` when created by a provisional response, `Confirmed` when
+/// created by (or updated with) a 2xx response.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DialogState {
+    Early,
+    Confirmed,
+}
+
+/// The dialog state of a UA, as defined in
+/// [RFC3261 section 12.1](https://datatracker.ietf.org/doc/html/rfc3261#section-12.1).
+///
+/// A dialog is created by a 101-199 response with a `To` tag (in `Early` state) or a 2xx response
+/// (in `Confirmed` state) to a dialog creating request, like an `INVITE`, using
+/// [uac](Dialog::uac) on the side that sent the request and [uas](Dialog::uas) on the side that
+/// received it.
+///
+/// The `secure` flag is set when the request was sent to a `sips` uri. Rsip has no knowledge of
+/// the transport, so it is up to the caller to make sure that the request was received over TLS.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dialog {
+    pub id: DialogId,
+    pub state: DialogState,
+    pub local_seq: Option<u32>,
+    pub remote_seq: Option<u32>,
+    pub local_uri: Uri,
+    pub remote_uri: Uri,
+    pub local_target: Uri,
+    pub remote_target: Uri,
+    pub secure: bool,
+    pub route_set: Vec<UriWithParams>,
+}
+
+impl Dialog {
+    /// Creates the dialog on the side that sent the `request`
+    /// ([section 12.1.2](https://datatracker.ietf.org/doc/html/rfc3261#section-12.1.2)), from
+    /// the `response` it received. The route set is the `Record-Route` of the response in reverse
+    /// order and the remote target is the `Contact` of the response.
+    pub fn uac(request: &Request, response: &Response) -> Result<Self, Error> {
+        let mut route_set = route_set(response)?;
+        route_set.reverse();
+
+        Ok(Self {
+            id: DialogId::uac(response)?,
+            state: state(response)?,
+            local_seq: Some(request.cseq_header()?.seq()?),
+            remote_seq: None,
+            local_uri: request.from_header()?.uri()?,
+            remote_uri: request.to_header()?.uri()?,
+            local_target: request.contact_header()?.uri()?,
+            remote_target: response.contact_header()?.uri()?,
+            secure: is_secure(request),
+            route_set,
+        })
+    }
+
+    /// Creates the dialog on the side that received the `request`
+    /// ([section 12.1.1](https://datatracker.ietf.org/doc/html/rfc3261#section-12.1.1)), from
+    /// the `response` it sends back, which holds the local tag in its `To` header. The route set
+    /// is the `Record-Route` of the request in order and the remote target is the `Contact` of
+    /// the request.
+    pub fn uas(request: &Request, response: &Response) -> Result<Self, Error> {
+        Ok(Self {
+            id: DialogId::uas(response)?,
+            state: state(response)?,
+            local_seq: None,
+            remote_seq: Some(request.cseq_header()?.seq()?),
+            local_uri: request.to_header()?.uri()?,
+            remote_uri: request.from_header()?.uri()?,
+            local_target: response.contact_header()?.uri()?,
+            remote_target: request.contact_header()?.uri()?,
+            secure: is_secure(request),
+            route_set: route_set(request)?,
+        })
+    }
+
+    /// Updates an `Early` dialog on the side that sent the request with a subsequent response of
+    /// the same dialog. A 2xx response confirms the dialog and recomputes the route set
+    /// ([section 13.2.2.4](https://datatracker.ietf.org/doc/html/rfc3261#section-13.2.2.4)),
+    /// while any response with a `Contact` refreshes the remote target.
+    pub fn update(&mut self, response: &Response) -> Result<(), Error> {
+        if DialogId::uac(response)? != self.id {
+            return Err(Error::Unexpected(
+                "response does not belong to this dialog".into(),
+            ));
+        }
+
+        let state = state(response)?;
+        if self.state == DialogState::Early && state == DialogState::Confirmed {
+            let mut route_set = route_set(response)?;
+            route_set.reverse();
+            self.route_set = route_set;
+            self.state = state;
+        }
+
+        if let Ok(contact) = response.contact_header() {
+            self.remote_target = contact.uri()?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates a request within the dialog, like a `BYE`, a re-`INVITE`, an `INFO` or an
+    /// `UPDATE`, as described in
+    /// [section 12.2.1.1](https://datatracker.ietf.org/doc/html/rfc3261#section-12.2.1.1).
+    ///
+    /// The local sequence number is incremented (starting from 1 if this side has not sent any
+    /// request yet) and the given `via` becomes the `Via` of the request with a fresh branch.
+    /// When the first element of the route set is a strict router (its uri has no `lr` param),
+    /// it becomes the request uri and the remote target is appended at the end of the `Route`
+    /// header, otherwise the remote target is the request uri. Target refresh requests carry the
+    /// local target in a `Contact` header. Any body, along with the `Content-Length` and any other
+    /// header, is left to the caller. For the `ACK` of a 2xx response use [ack](Dialog::ack).
+    pub fn request(&mut self, method: Method, via: typed::Via) -> Request {
+        let seq = self.local_seq.map_or(1, |seq| seq + 1);
+        self.local_seq = Some(seq);
+
+        self.request_with_seq(method, seq, via)
+    }
+
+    /// Generates the `ACK` of a 2xx response to an `INVITE`
+    /// ([section 13.2.2.4](https://datatracker.ietf.org/doc/html/rfc3261#section-13.2.2.4)),
+    /// which is built like any other request within the dialog, but has the sequence number of the
+    /// `INVITE` and does not increment the local sequence number. The credentials of the `INVITE`
+    /// are left to the caller, see [Request::ack](super::Request::ack).
+    pub fn ack(&self, invite_seq: u32, via: typed::Via) -> Request {
+        self.request_with_seq(Method::Ack, invite_seq, via)
+    }
+
+    fn request_with_seq(&self, method: Method, seq: u32, mut via: typed::Via) -> Request {
+        via.params
+            .retain(|param| !matches!(param, Param::Branch(_)));
+        via.params.insert(0, Param::Branch(Branch::default()));
+
+        let (uri, route) = match self.route_set.split_first() {
+            Some((first, rest)) if !first.uri.params.contains(&Param::Lr) => {
+                let mut uri = first.uri.clone();
+                uri.params
+                    .retain(|param| !matches!(param, Param::Method(_)));
+                uri.headers.clear();
+
+                let mut route = rest.to_vec();
+                route.push(UriWithParams {
+                    uri: self.remote_target.clone(),
+                    params: vec![],
+                });
+                (uri, route)
+            }
+            _ => (self.remote_target.clone(), self.route_set.clone()),
+        };
+
+        let mut headers: Headers = Default::default();
+        headers.push(via.into());
+        if !route.is_empty() {
+            headers.push(typed::Route::from(UriWithParamsList::from(route)).into());
+        }
+        headers.push(MaxForwards::default().into());
+        headers.push(
+            typed::From {
+                display_name: None,
+                uri: self.local_uri.clone(),
+                params: vec![Param::Tag(self.id.local_tag.clone())],
+            }
+            .into(),
+        );
+        headers.push(
+            typed::To {
+                display_name: None,
+                uri: self.remote_uri.clone(),
+                params: vec![Param::Tag(self.id.remote_tag.clone())],
+            }
+            .into(),
+        );
+        headers.push(self.id.call_id.clone().into());
+        headers.push(typed::CSeq { seq, method }.into());
+        if is_target_refresh(method) {
+            headers.push(
+                typed::Contact {
+                    display_name: None,
+                    uri: self.local_target.clone(),
+                    params: vec![],
+                }
+                .into(),
+            );
+        }
+
+        Request {
+            method,
+            uri,
+            version: Version::V2,
+            headers,
+            body: vec![],
+        }
+    }
+
+    pub fn is_early(&self) -> bool {
+        self.state == DialogState::Early
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.state == DialogState::Confirmed
+    }
+}
+
+fn from_tag(message: &impl HeadersExt) -> Result<Tag, Error> {
+    message
+        .from_header()?
+        .tag()?
+        .ok_or_else(|| Error::missing_param("tag"))
+}
+
+fn to_tag(message: &impl HeadersExt) -> Result<Tag, Error> {
+    message
+        .to_header()?
+        .tag()?
+        .ok_or_else(|| Error::missing_param("tag"))
+}
+
+fn state(response: &Response) -> Result<DialogState, Error> {
+    match response.status_code.kind() {
+        StatusCodeKind::Provisional if response.status_code.code() > 100 => Ok(DialogState::Early),
+        StatusCodeKind::Successful => Ok(DialogState::Confirmed),
+        _ => Err(Error::Unexpected(format!(
+            "dialogs are created only by 101-199 and 2xx responses, got {}",
+            response.status_code
+        ))),
+    }
+}
+
+fn route_set(message: &impl HeadersExt) -> Result<Vec<UriWithParams>, Error> {
+    message
+        .record_route_headers()
+        .into_iter()
+        .map(|header| Ok(header.typed()?.uris().to_vec()))
+        .collect::<Result<Vec<_>, Error>>()
+        .map(|uris| uris.into_iter().flatten().collect())
+}
+
+fn is_target_refresh(method: Method) -> bool {
+    matches!(
+        method,
+        Method::Invite | Method::Update | Method::Subscribe | Method::Notify | Method::Refer
+    )
+}
+
+fn is_secure(request: &Request) -> bool {
+    request.uri.scheme == Some(Scheme::Sips)
+}
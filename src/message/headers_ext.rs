@@ -1,9 +1,16 @@
 use crate::{
-    headers::{self, Header},
+    headers::{self, Header, Key, UntypedHeader},
     param::Branch,
     Error,
 };
 
+//the key of the given kind of header, to look it up through the index of the headers
+macro_rules! key {
+    ($header:ident) => {
+        Key::of(&Header::$header(headers::$header::new("")))
+    };
+}
+
 /// Helpful trait to access most common headers from a [Request](crate::Request),
 /// [Response](crate::Response) and [SipMessage](crate::SipMessage) structs.
 ///
@@ -12,7 +19,7 @@ use crate::{
 pub trait HeadersExt: super::HasHeaders {
     fn to_header(&self) -> Result<&headers::To, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(To)),
             Header::To,
             Error::missing_header("To")
         )
@@ -20,7 +27,7 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn to_header_mut(&mut self) -> Result<&mut headers::To, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut().lookup_mut(&key!(To)).into_iter(),
             Header::To,
             Error::missing_header("To")
         )
@@ -29,7 +36,7 @@ pub trait HeadersExt: super::HasHeaders {
     #[allow(clippy::wrong_self_convention)]
     fn from_header(&self) -> Result<&headers::From, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(From)),
             Header::From,
             Error::missing_header("From")
         )
@@ -38,7 +45,7 @@ pub trait HeadersExt: super::HasHeaders {
     #[allow(clippy::wrong_self_convention)]
     fn from_header_mut(&mut self) -> Result<&mut headers::From, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut().lookup_mut(&key!(From)).into_iter(),
             Header::From,
             Error::missing_header("From")
         )
@@ -46,14 +53,14 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn via_header(&self) -> Result<&headers::Via, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(Via)),
             Header::Via,
             Error::missing_header("Via")
         )
     }
     fn via_header_mut(&mut self) -> Result<&mut headers::Via, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut().lookup_mut(&key!(Via)).into_iter(),
             Header::Via,
             Error::missing_header("Via")
         )
@@ -61,14 +68,14 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn call_id_header(&self) -> Result<&headers::CallId, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(CallId)),
             Header::CallId,
             Error::missing_header("CallID")
         )
     }
     fn call_id_header_mut(&mut self) -> Result<&mut headers::CallId, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut().lookup_mut(&key!(CallId)).into_iter(),
             Header::CallId,
             Error::missing_header("CallID")
         )
@@ -76,14 +83,14 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn cseq_header(&self) -> Result<&headers::CSeq, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(CSeq)),
             Header::CSeq,
             Error::missing_header("CSeq")
         )
     }
     fn cseq_header_mut(&mut self) -> Result<&mut headers::CSeq, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut().lookup_mut(&key!(CSeq)).into_iter(),
             Header::CSeq,
             Error::missing_header("CSeq")
         )
@@ -91,14 +98,16 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn max_forwards_header(&self) -> Result<&headers::MaxForwards, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(MaxForwards)),
             Header::MaxForwards,
             Error::missing_header("Max-Forwards")
         )
     }
     fn max_forwards_header_mut(&mut self) -> Result<&mut headers::MaxForwards, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut()
+                .lookup_mut(&key!(MaxForwards))
+                .into_iter(),
             Header::MaxForwards,
             Error::missing_header("Max-Forwards")
         )
@@ -106,7 +115,7 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn contact_header(&self) -> Result<&headers::Contact, Error> {
         header!(
-            self.headers().iter(),
+            self.headers().lookup(&key!(Contact)),
             Header::Contact,
             Error::missing_header("Contact")
         )
@@ -114,46 +123,95 @@ pub trait HeadersExt: super::HasHeaders {
 
     fn contact_header_mut(&mut self) -> Result<&mut headers::Contact, Error> {
         header!(
-            self.headers_mut().iter_mut(),
+            self.headers_mut().lookup_mut(&key!(Contact)).into_iter(),
             Header::Contact,
             Error::missing_header("Contact")
         )
     }
 
     fn contact_headers(&self) -> Vec<&headers::Contact> {
-        all_headers!(self.headers().iter(), Header::Contact)
+        all_headers!(self.headers().lookup(&key!(Contact)), Header::Contact)
     }
 
     fn record_route_header(&self) -> Option<&headers::RecordRoute> {
-        header_opt!(self.headers().iter(), Header::RecordRoute)
+        header_opt!(
+            self.headers().lookup(&key!(RecordRoute)),
+            Header::RecordRoute
+        )
+    }
+
+    fn record_route_headers(&self) -> Vec<&headers::RecordRoute> {
+        all_headers!(
+            self.headers().lookup(&key!(RecordRoute)),
+            Header::RecordRoute
+        )
     }
 
     fn route_header(&self) -> Option<&headers::Route> {
-        header_opt!(self.headers().iter(), Header::Route)
+        header_opt!(self.headers().lookup(&key!(Route)), Header::Route)
+    }
+
+    fn route_headers(&self) -> Vec<&headers::Route> {
+        all_headers!(self.headers().lookup(&key!(Route)), Header::Route)
     }
 
     fn user_agent_header(&self) -> Option<&headers::UserAgent> {
-        header_opt!(self.headers().iter(), Header::UserAgent)
+        header_opt!(self.headers().lookup(&key!(UserAgent)), Header::UserAgent)
     }
 
     fn authorization_header(&self) -> Option<&headers::Authorization> {
-        header_opt!(self.headers().iter(), Header::Authorization)
+        header_opt!(
+            self.headers().lookup(&key!(Authorization)),
+            Header::Authorization
+        )
     }
 
     fn www_authenticate_header(&self) -> Option<&headers::WwwAuthenticate> {
-        header_opt!(self.headers().iter(), Header::WwwAuthenticate)
+        header_opt!(
+            self.headers().lookup(&key!(WwwAuthenticate)),
+            Header::WwwAuthenticate
+        )
+    }
+
+    fn www_authenticate_headers(&self) -> Vec<&headers::WwwAuthenticate> {
+        all_headers!(
+            self.headers().lookup(&key!(WwwAuthenticate)),
+            Header::WwwAuthenticate
+        )
+    }
+
+    fn proxy_authenticate_header(&self) -> Option<&headers::ProxyAuthenticate> {
+        header_opt!(
+            self.headers().lookup(&key!(ProxyAuthenticate)),
+            Header::ProxyAuthenticate
+        )
+    }
+
+    fn proxy_authenticate_headers(&self) -> Vec<&headers::ProxyAuthenticate> {
+        all_headers!(
+            self.headers().lookup(&key!(ProxyAuthenticate)),
+            Header::ProxyAuthenticate
+        )
+    }
+
+    fn content_length_header(&self) -> Option<&headers::ContentLength> {
+        header_opt!(
+            self.headers().lookup(&key!(ContentLength)),
+            Header::ContentLength
+        )
     }
 
     fn expires_header(&self) -> Option<&headers::Expires> {
-        header_opt!(self.headers().iter(), Header::Expires)
+        header_opt!(self.headers().lookup(&key!(Expires)), Header::Expires)
     }
 
     fn min_expires_header(&self) -> Option<&headers::MinExpires> {
-        header_opt!(self.headers().iter(), Header::MinExpires)
+        header_opt!(self.headers().lookup(&key!(MinExpires)), Header::MinExpires)
     }
 
-    //TODO: this is not correct? this is just the branch id
-    //however RFC3261 states clear ways on how transactions should be matched
+    /// Returns just the branch of the top `Via` header. For matching messages to transactions
+    /// use [TransactionKey](super::TransactionKey) instead, that follows RFC3261 rules.
+    #[deprecated(note = "use rsip::message::TransactionKey instead")]
     fn transaction_id(&self) -> Result<Branch, Error> {
         self.via_header()?.branch()
     }
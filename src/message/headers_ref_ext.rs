@@ -0,0 +1,153 @@
+use crate::{
+    headers::{
+        Authorization, CSeq, CallId, Contact, ContentLength, Expires, From, HeaderRef, MaxForwards,
+        MinExpires, ProxyAuthenticate, RecordRoute, Route, To, UserAgent, Via, WwwAuthenticate,
+    },
+    Error,
+};
+
+/// The borrowed counterpart of [HeadersExt](super::HeadersExt), to access the most common headers
+/// from a [RequestRef](super::RequestRef), [ResponseRef](super::ResponseRef) and
+/// [SipMessageRef](super::SipMessageRef), matching the header names (and their compact forms)
+/// case insensitively.
+pub trait HeadersRefExt<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>];
+
+    fn to_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), To::NAME, To::COMPACT_NAME).ok_or_else(|| Error::missing_header("To"))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), From::NAME, From::COMPACT_NAME)
+            .ok_or_else(|| Error::missing_header("From"))
+    }
+
+    fn via_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), Via::NAME, Via::COMPACT_NAME)
+            .ok_or_else(|| Error::missing_header("Via"))
+    }
+
+    fn via_headers(&self) -> Vec<HeaderRef<'a>> {
+        find_all(self.headers(), Via::NAME, Via::COMPACT_NAME)
+    }
+
+    fn call_id_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), CallId::NAME, CallId::COMPACT_NAME)
+            .ok_or_else(|| Error::missing_header("CallID"))
+    }
+
+    fn cseq_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), CSeq::NAME, CSeq::COMPACT_NAME)
+            .ok_or_else(|| Error::missing_header("CSeq"))
+    }
+
+    fn max_forwards_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), MaxForwards::NAME, MaxForwards::COMPACT_NAME)
+            .ok_or_else(|| Error::missing_header("Max-Forwards"))
+    }
+
+    fn contact_header(&self) -> Result<HeaderRef<'a>, Error> {
+        find(self.headers(), Contact::NAME, Contact::COMPACT_NAME)
+            .ok_or_else(|| Error::missing_header("Contact"))
+    }
+
+    fn contact_headers(&self) -> Vec<HeaderRef<'a>> {
+        find_all(self.headers(), Contact::NAME, Contact::COMPACT_NAME)
+    }
+
+    fn record_route_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), RecordRoute::NAME, RecordRoute::COMPACT_NAME)
+    }
+
+    fn record_route_headers(&self) -> Vec<HeaderRef<'a>> {
+        find_all(self.headers(), RecordRoute::NAME, RecordRoute::COMPACT_NAME)
+    }
+
+    fn route_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), Route::NAME, Route::COMPACT_NAME)
+    }
+
+    fn route_headers(&self) -> Vec<HeaderRef<'a>> {
+        find_all(self.headers(), Route::NAME, Route::COMPACT_NAME)
+    }
+
+    fn user_agent_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), UserAgent::NAME, UserAgent::COMPACT_NAME)
+    }
+
+    fn authorization_header(&self) -> Option<HeaderRef<'a>> {
+        find(
+            self.headers(),
+            Authorization::NAME,
+            Authorization::COMPACT_NAME,
+        )
+    }
+
+    fn www_authenticate_header(&self) -> Option<HeaderRef<'a>> {
+        find(
+            self.headers(),
+            WwwAuthenticate::NAME,
+            WwwAuthenticate::COMPACT_NAME,
+        )
+    }
+
+    fn www_authenticate_headers(&self) -> Vec<HeaderRef<'a>> {
+        find_all(
+            self.headers(),
+            WwwAuthenticate::NAME,
+            WwwAuthenticate::COMPACT_NAME,
+        )
+    }
+
+    fn proxy_authenticate_header(&self) -> Option<HeaderRef<'a>> {
+        find(
+            self.headers(),
+            ProxyAuthenticate::NAME,
+            ProxyAuthenticate::COMPACT_NAME,
+        )
+    }
+
+    fn proxy_authenticate_headers(&self) -> Vec<HeaderRef<'a>> {
+        find_all(
+            self.headers(),
+            ProxyAuthenticate::NAME,
+            ProxyAuthenticate::COMPACT_NAME,
+        )
+    }
+
+    fn content_length_header(&self) -> Option<HeaderRef<'a>> {
+        find(
+            self.headers(),
+            ContentLength::NAME,
+            ContentLength::COMPACT_NAME,
+        )
+    }
+
+    fn expires_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), Expires::NAME, Expires::COMPACT_NAME)
+    }
+
+    fn min_expires_header(&self) -> Option<HeaderRef<'a>> {
+        find(self.headers(), MinExpires::NAME, MinExpires::COMPACT_NAME)
+    }
+}
+
+fn find<'a>(headers: &[HeaderRef<'a>], name: &str, compact: Option<&str>) -> Option<HeaderRef<'a>> {
+    headers
+        .iter()
+        .copied()
+        .find(|header| header.is(name, compact))
+}
+
+fn find_all<'a>(
+    headers: &[HeaderRef<'a>],
+    name: &str,
+    compact: Option<&str>,
+) -> Vec<HeaderRef<'a>> {
+    headers
+        .iter()
+        .copied()
+        .filter(|header| header.is(name, compact))
+        .collect()
+}
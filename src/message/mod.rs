@@ -1,14 +1,28 @@
 #[macro_use]
 pub mod header_macros;
+pub mod dialog;
 pub mod headers_ext;
+pub mod headers_ref_ext;
+pub mod parse_options;
 pub mod request;
 pub mod response;
 pub mod sip_message;
+pub mod sip_message_ref;
+pub mod stream_decoder;
+pub mod transaction_key;
+pub mod validation;
 
+pub use dialog::{Dialog, DialogId, DialogState};
 pub use headers_ext::HeadersExt;
+pub use headers_ref_ext::HeadersRefExt;
+pub use parse_options::ParseOptions;
 pub use request::Request;
 pub use response::Response;
 pub use sip_message::SipMessage;
+pub use sip_message_ref::{RequestRef, ResponseRef, SipMessageRef};
+pub use stream_decoder::{Decoded, StreamDecoder};
+pub use transaction_key::TransactionKey;
+pub use validation::Violation;
 
 /// Simple trait to signify that the underlying type has headers so that the
 /// [HeadersExt](crate::message::HeadersExt) trait
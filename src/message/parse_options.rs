@@ -0,0 +1,122 @@
+use crate::{
+    error::{Locate, Locator},
+    headers::{header, Header, Headers},
+    Component, Error, PositionedError,
+};
+use std::borrow::Cow;
+
+/// Options for parsing a SIP message through [Request::parse_with](crate::Request::parse_with),
+/// [Response::parse_with](crate::Response::parse_with) and
+/// [SipMessage::parse_with](crate::SipMessage::parse_with).
+///
+/// By default parsing is strict, like the `TryFrom` conversions. In lenient mode parsing recovers
+/// from the most common mistakes of buggy UAs:
+/// * lines can end with a bare LF instead of CRLF.
+/// * whitespace is allowed between a header name and the colon.
+/// * a header line that can't be tokenized (or is not valid utf8) is kept in its raw form as a
+///   [Header::Invalid](crate::Header::Invalid) instead of failing the whole message, and a
+///   [PositionedError] for it is returned along with the message.
+///
+/// The start line still needs to be valid in lenient mode.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ParseOptions {
+    pub lenient: bool,
+}
+
+impl ParseOptions {
+    pub fn strict() -> Self {
+        Self { lenient: false }
+    }
+
+    pub fn lenient() -> Self {
+        Self { lenient: true }
+    }
+}
+
+// The lines of a message head, split on CRLF or bare LF, where any folded lines are kept along
+// with the header line they belong to.
+pub(crate) struct Lines<'a> {
+    pub start_line: &'a [u8],
+    pub headers: Vec<&'a [u8]>,
+    pub body: &'a [u8],
+}
+
+impl<'a> Lines<'a> {
+    pub fn split(input: &'a [u8]) -> Self {
+        let mut lines: Vec<(usize, usize)> = vec![];
+        let mut start = 0;
+
+        let body_start = loop {
+            let end = match input[start..].iter().position(|c| *c == b'\n') {
+                Some(position) => start + position,
+                None => {
+                    if start < input.len() {
+                        lines.push((start, input.len()));
+                    }
+                    break input.len();
+                }
+            };
+            let line_end = match end > start && input[end - 1] == b'\r' {
+                true => end - 1,
+                false => end,
+            };
+
+            match (line_end == start, input.get(start)) {
+                (true, _) => break end + 1,
+                (false, Some(b' ' | b'\t')) if lines.len() > 1 => {
+                    if let Some(last) = lines.last_mut() {
+                        last.1 = line_end;
+                    }
+                }
+                _ => lines.push((start, line_end)),
+            }
+            start = end + 1;
+        };
+
+        let mut lines = lines.into_iter().map(|(start, end)| &input[start..end]);
+
+        Self {
+            start_line: lines.next().unwrap_or_default(),
+            headers: lines.collect(),
+            body: &input[body_start..],
+        }
+    }
+
+    pub fn headers(&self, locator: &Locator) -> (Headers, Vec<PositionedError>) {
+        let mut diagnostics = vec![];
+
+        let headers = self
+            .headers
+            .iter()
+            .map(|line| match header(locator, line) {
+                Ok(header) => header,
+                Err(error) => {
+                    diagnostics.push(error);
+                    Header::Invalid(String::from_utf8_lossy(line).into())
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (headers.into(), diagnostics)
+    }
+}
+
+fn header(locator: &Locator, line: &[u8]) -> Result<Header, PositionedError> {
+    let (_, tokenizer) = header::Tokenizer::tokenize_lenient(line).map_err(|_| {
+        locator.locate(line, Component::Headers, Error::tokenizer(("header", line)))
+    })?;
+
+    //folded lines are unfolded on conversion only when separated by CRLF
+    let value: Cow<[u8]> = match tokenizer.value.contains(&b'\n') {
+        true => tokenizer
+            .value
+            .split(|c| *c == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join(&b"\r\n"[..])
+            .into(),
+        false => tokenizer.value.into(),
+    };
+
+    header::Tokenizer::from((tokenizer.name, value.as_ref())).convert(locator)
+}
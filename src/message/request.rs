@@ -4,11 +4,16 @@ pub use tokenizer::Tokenizer;
 use crate::{
     common::{
         method::{self, Method},
+        status_code::StatusCode,
         uri::{self, Uri},
         version::{self, Version},
     },
-    headers::{header, Headers},
-    Error, SipMessage,
+    error::{Component, Locator, TokenizeFailure},
+    headers::{
+        header, typed, untyped::ToTypedHeader, ContentLength, Header, Headers, MaxForwards, To,
+    },
+    message::{parse_options::Lines, Dialog, HeadersExt, ParseOptions},
+    Error, PositionedError, Response, SipMessage, StatusCodeKind,
 };
 use std::convert::{TryFrom, TryInto};
 
@@ -25,6 +30,7 @@ use std::convert::{TryFrom, TryInto};
 /// that has implemented the [HasHeaders](crate::message::HasHeaders) trait, which Request
 /// implements it.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Request {
     pub method: Method,
     pub uri: Uri,
@@ -53,6 +59,203 @@ impl Request {
     pub fn body_mut(&mut self) -> &mut Vec<u8> {
         &mut self.body
     }
+
+    /// Parses a request like the `TryFrom` conversions do, but on failure returns a
+    /// [PositionedError] that points to where in the input the error occurred.
+    pub fn parse(input: &[u8]) -> Result<Self, PositionedError> {
+        let locator = Locator::new(input);
+
+        Tokenizer::tokenize_located(input)
+            .map_err(|failure| locator.tokenizer(failure))?
+            .1
+            .convert(&locator)
+    }
+
+    /// Parses a request with the given [ParseOptions], returning along with it a
+    /// [PositionedError] for each header line that lenient parsing recovered from.
+    pub fn parse_with(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<PositionedError>), PositionedError> {
+        use crate::parser_utils::is_empty_or_fail_with;
+
+        if !options.lenient {
+            return Ok((Self::parse(input)?, vec![]));
+        }
+
+        let locator = Locator::new(input);
+        let lines = Lines::split(input);
+
+        let (rem, (method, uri, version)) = Tokenizer::tokenize_start_line(lines.start_line)
+            .map_err(|failure| locator.tokenizer(failure))?;
+        is_empty_or_fail_with(rem, ("start line", rem))
+            .map_err(TokenizeFailure::trailing(rem, Component::StartLine))
+            .map_err(|failure| locator.tokenizer(failure))?;
+
+        let (headers, diagnostics) = lines.headers(&locator);
+        let tokenizer = Tokenizer {
+            method,
+            uri,
+            version,
+            headers: vec![],
+            body: lines.body,
+        };
+
+        Ok((
+            Self {
+                headers,
+                ..tokenizer.convert(&locator)?
+            },
+            diagnostics,
+        ))
+    }
+
+    /// Checks the request against the rules of RFC3261 that parsing does not enforce, returning
+    /// all [Violation](super::Violation)s found, if any.
+    pub fn validate(&self) -> Vec<super::Violation> {
+        super::validation::validate(&self.headers, &self.body, Some(self.method))
+    }
+
+    /// Creates a response to this request, as described in
+    /// [RFC3261 section 8.2.6](https://datatracker.ietf.org/doc/html/rfc3261#section-8.2.6).
+    ///
+    /// The `Via` (in the same order), `From`, `To`, `Call-ID` and `CSeq` headers are copied from
+    /// the request and a zero `Content-Length` is added. A `100 Trying` response also copies the
+    /// `Timestamp` header, if any, while a 101-299 response to a request that can create a dialog
+    /// (`INVITE`, `SUBSCRIBE`, `REFER` or `NOTIFY`) also copies the `Record-Route` headers, as
+    /// described in [section 12.1.1](https://datatracker.ietf.org/doc/html/rfc3261#section-12.1.1).
+    /// The `To` tag is left as is, use
+    /// [with_to_tag](Response::with_to_tag) to add one:
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// # let request = rsip::Request::try_from(concat!(
+    /// #     "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    /// #     "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    /// #     "To: Bob <sip:bob@biloxi.com>\r\n",
+    /// #     "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    /// #     "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    /// #     "CSeq: 314159 INVITE\r\n\r\n"
+    /// # )).unwrap();
+    /// let ringing = request
+    ///     .response(rsip::StatusCode::Ringing)
+    ///     .with_to_tag(rsip::param::Tag::default())
+    ///     .unwrap();
+    /// ```
+    pub fn response(&self, status_code: StatusCode) -> Response {
+        let is_trying = status_code == StatusCode::Trying;
+        let creates_dialog = matches!(
+            self.method,
+            Method::Invite | Method::Subscribe | Method::Refer | Method::Notify
+        ) && (101..300).contains(&status_code.code());
+
+        let mut headers: Headers = self
+            .headers
+            .iter()
+            .filter(|header| match header {
+                Header::Via(_)
+                | Header::From(_)
+                | Header::To(_)
+                | Header::CallId(_)
+                | Header::CSeq(_) => true,
+                Header::Timestamp(_) => is_trying,
+                Header::RecordRoute(_) => creates_dialog,
+                _ => false,
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+            .into();
+        headers.push(ContentLength::default().into());
+
+        Response {
+            status_code,
+            version: self.version.clone(),
+            headers,
+            body: vec![],
+        }
+    }
+
+    /// Creates the `CANCEL` of this (pending) `INVITE`, as described in
+    /// [RFC3261 section 9.1](https://datatracker.ietf.org/doc/html/rfc3261#section-9.1). It has the
+    /// same request uri, top `Via` (thus same branch), `Route`, `From`, `To`, `Call-ID` and `CSeq`
+    /// number as the `INVITE`.
+    pub fn cancel(&self) -> Result<Request, Error> {
+        self.invite_transaction_request(Method::Cancel, self.to_header()?.clone())
+    }
+
+    /// Creates the `ACK` of the given final response to this `INVITE`.
+    ///
+    /// For a non-2xx response the `ACK` is part of the `INVITE` client transaction
+    /// ([RFC3261 section 17.1.1.3](https://datatracker.ietf.org/doc/html/rfc3261#section-17.1.1.3)),
+    /// so it is built like a [cancel](Request::cancel) (same top `Via`) but with the `To` header
+    /// of the response. For a 2xx response the `ACK` is a new transaction within the dialog the
+    /// response created ([section 13.2.2.4](https://datatracker.ietf.org/doc/html/rfc3261#section-13.2.2.4)),
+    /// hence it goes to the remote target using the route set of the dialog and the top `Via` of
+    /// the `INVITE` with a new branch, see [Dialog::ack]. It also carries the same credentials
+    /// (`Authorization` and `Proxy-Authorization` headers) as the `INVITE`.
+    pub fn ack(&self, response: &Response) -> Result<Request, Error> {
+        match response.status_code.kind() {
+            StatusCodeKind::Provisional => {
+                Err(Error::Unexpected("can't ACK a provisional response".into()))
+            }
+            StatusCodeKind::Successful => {
+                self.ensure_invite()?;
+                let mut ack = Dialog::uac(self, response)?
+                    .ack(self.cseq_header()?.seq()?, self.via_header()?.typed()?);
+                for header in self.headers.iter().filter(|header| {
+                    matches!(
+                        header,
+                        Header::Authorization(_) | Header::ProxyAuthorization(_)
+                    )
+                }) {
+                    ack.headers.push(header.clone());
+                }
+                ack.headers.push(ContentLength::default().into());
+                Ok(ack)
+            }
+            _ => self.invite_transaction_request(Method::Ack, response.to_header()?.clone()),
+        }
+    }
+
+    fn invite_transaction_request(&self, method: Method, to: To) -> Result<Request, Error> {
+        self.ensure_invite()?;
+
+        let mut headers: Headers = Default::default();
+        headers.push(self.via_header()?.clone().into());
+        for route in self.route_headers() {
+            headers.push(route.clone().into());
+        }
+        headers.push(MaxForwards::default().into());
+        headers.push(self.from_header()?.clone().into());
+        headers.push(to.into());
+        headers.push(self.call_id_header()?.clone().into());
+        headers.push(
+            typed::CSeq {
+                seq: self.cseq_header()?.seq()?,
+                method,
+            }
+            .into(),
+        );
+        headers.push(ContentLength::default().into());
+
+        Ok(Request {
+            method,
+            uri: self.uri.clone(),
+            version: self.version.clone(),
+            headers,
+            body: vec![],
+        })
+    }
+
+    fn ensure_invite(&self) -> Result<(), Error> {
+        match self.method {
+            Method::Invite => Ok(()),
+            method => Err(Error::Unexpected(format!(
+                "expected an INVITE request, got {}",
+                method
+            ))),
+        }
+    }
 }
 
 impl super::HasHeaders for Request {
@@ -65,17 +268,13 @@ impl super::HasHeaders for Request {
     }
 }
 
+/// Using the alternate flag (`{:#}`) prints the headers in their compact form, if any, see
+/// [Headers](crate::Headers).
 impl std::fmt::Display for Request {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {} {}\r\n{}\r\n{}",
-            self.method,
-            self.uri,
-            self.version,
-            self.headers,
-            String::from_utf8_lossy(&self.body)
-        )
+        write!(f, "{} {} {}\r\n", self.method, self.uri, self.version)?;
+        self.headers.fmt(f)?;
+        write!(f, "\r\n{}", String::from_utf8_lossy(&self.body))
     }
 }
 
@@ -152,26 +351,19 @@ impl From<Request> for bytes::Bytes {
 
 #[doc(hidden)]
 pub mod tokenizer {
-    use super::{header, method, uri, version, Request};
-    use crate::{Error, IResult, NomError, TokenizerError};
+    use super::{header, method, uri, version, Request, Uri};
+    use crate::{
+        error::{Locate, TokenizeFailure, Unlocated},
+        Component, Error, IResult, TokenizerError, UriPart,
+    };
+    use nom::bytes::complete::tag;
     use std::convert::TryInto;
 
     impl<'a> TryInto<Request> for Tokenizer<'a> {
         type Error = Error;
 
         fn try_into(self) -> Result<Request, Error> {
-            Ok(Request {
-                method: self.method.try_into()?,
-                uri: self.uri.try_into()?,
-                version: self.version.try_into()?,
-                headers: self
-                    .headers
-                    .into_iter()
-                    .map(TryInto::try_into)
-                    .collect::<Result<Vec<_>, Error>>()?
-                    .into(),
-                body: self.body.into(),
-            })
+            self.convert(&Unlocated)
         }
     }
 
@@ -186,30 +378,17 @@ pub mod tokenizer {
 
     impl<'a> Tokenizer<'a> {
         pub fn tokenize(part: &'a [u8]) -> IResult<Self> {
-            use crate::parser_utils::is_empty_or_fail_with;
-            use nom::{
-                branch::alt,
-                bytes::complete::{tag, take_until},
-                multi::many0,
-                sequence::tuple,
-            };
-
-            let (rem, (method, _, uri, _, version, _)) = tuple((
-                method::Tokenizer::tokenize,
-                tag(" "),
-                uri::Tokenizer::tokenize,
-                tag(" "),
-                version::Tokenizer::tokenize,
-                tag("\r\n"),
-            ))(part)?;
-
-            let (body, (raw_headers, _)) = alt((
-                tuple((take_until("\r\n\r\n"), tag("\r\n\r\n"))),
-                tuple((take_until("\r\n"), tag("\r\n"))),
-            ))(rem)
-            .map_err(|_: NomError<'a>| TokenizerError::from(("headers", rem)).into())?;
-            let (rem, headers) = many0(header::Tokenizer::tokenize)(raw_headers)?;
-            is_empty_or_fail_with(rem, ("headers", rem))?;
+            Self::tokenize_located(part).map_err(|failure| failure.error)
+        }
+
+        //like tokenize, but on failure it also tells the part of the input that failed
+        pub(crate) fn tokenize_located(
+            part: &'a [u8],
+        ) -> Result<(&'a [u8], Self), TokenizeFailure<'a>> {
+            let (rem, (method, uri, version)) = Self::tokenize_start_line(part)?;
+            let (rem, _) = tag::<_, _, TokenizerError>("\r\n")(rem)
+                .map_err(TokenizeFailure::of(rem, Component::StartLine))?;
+            let (body, headers) = header::Tokenizer::tokenize_headers(rem)?;
 
             Ok((
                 &[],
@@ -222,5 +401,54 @@ pub mod tokenizer {
                 },
             ))
         }
+
+        #[allow(clippy::type_complexity)]
+        pub(crate) fn tokenize_start_line(
+            part: &'a [u8],
+        ) -> Result<
+            (
+                &'a [u8],
+                (
+                    method::Tokenizer<'a, &'a [u8], u8>,
+                    uri::Tokenizer<'a, &'a [u8], u8>,
+                    version::Tokenizer<'a, &'a [u8], u8>,
+                ),
+            ),
+            TokenizeFailure<'a>,
+        > {
+            let (rem, method) = method::Tokenizer::tokenize(part)
+                .map_err(TokenizeFailure::of(part, Component::Method))?;
+            let (rem, _) = tag::<_, _, TokenizerError>(" ")(rem)
+                .map_err(TokenizeFailure::of(rem, Component::StartLine))?;
+            let (rem, uri) = uri::Tokenizer::tokenize(rem).map_err(TokenizeFailure::of(
+                rem,
+                Component::Uri(UriPart::HostWithPort),
+            ))?;
+            let (rem, _) = tag::<_, _, TokenizerError>(" ")(rem)
+                .map_err(TokenizeFailure::of(rem, Component::StartLine))?;
+            let (rem, version) = version::Tokenizer::tokenize(rem)
+                .map_err(TokenizeFailure::of(rem, Component::Version))?;
+
+            Ok((rem, (method, uri, version)))
+        }
+
+        pub(crate) fn convert<L: Locate>(self, locator: &L) -> Result<Request, L::Error> {
+            Ok(Request {
+                method: locator.convert(self.method.value, || Component::Method, self.method)?,
+                uri: Uri::convert(self.uri, locator)?,
+                version: locator.convert(
+                    self.version.major,
+                    || Component::Version,
+                    self.version,
+                )?,
+                headers: self
+                    .headers
+                    .into_iter()
+                    .map(|header| header.convert(locator))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into(),
+                body: self.body.into(),
+            })
+        }
     }
 }
@@ -2,12 +2,15 @@
 pub use tokenizer::Tokenizer;
 
 use crate::{
+    common::uri::param::Tag,
     common::{
         status_code::{self, StatusCode},
         version::{self, Version},
     },
-    headers::{header, Headers},
-    Error, SipMessage,
+    error::Locator,
+    headers::{header, typed, Headers, UntypedHeader},
+    message::{parse_options::Lines, HeadersExt, ParseOptions},
+    Error, PositionedError, SipMessage,
 };
 use std::convert::{TryFrom, TryInto};
 
@@ -24,6 +27,7 @@ use std::convert::{TryFrom, TryInto};
 /// that has implemented the [HasHeaders](crate::message::HasHeaders) trait, which Response
 /// implements it.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Response {
     pub status_code: StatusCode,
     pub version: Version,
@@ -47,6 +51,103 @@ impl Response {
     pub fn body_mut(&mut self) -> &mut Vec<u8> {
         &mut self.body
     }
+
+    /// Parses a response like the `TryFrom` conversions do, but on failure returns a
+    /// [PositionedError] that points to where in the input the error occurred.
+    pub fn parse(input: &[u8]) -> Result<Self, PositionedError> {
+        let locator = Locator::new(input);
+
+        Tokenizer::tokenize_located(input)
+            .map_err(|failure| locator.tokenizer(failure))?
+            .1
+            .convert(&locator)
+    }
+
+    /// Parses a response with the given [ParseOptions], returning along with it a
+    /// [PositionedError] for each header line that lenient parsing recovered from.
+    pub fn parse_with(
+        input: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<PositionedError>), PositionedError> {
+        if !options.lenient {
+            return Ok((Self::parse(input)?, vec![]));
+        }
+
+        let locator = Locator::new(input);
+        let lines = Lines::split(input);
+
+        let (_, (version, status_code)) = Tokenizer::tokenize_start_line(lines.start_line)
+            .map_err(|failure| locator.tokenizer(failure))?;
+
+        let (headers, diagnostics) = lines.headers(&locator);
+        let tokenizer = Tokenizer {
+            version,
+            status_code,
+            headers: vec![],
+            body: lines.body,
+        };
+
+        Ok((
+            Self {
+                headers,
+                ..tokenizer.convert(&locator)?
+            },
+            diagnostics,
+        ))
+    }
+
+    /// Checks the response against the rules of RFC3261 that parsing does not enforce, returning
+    /// all [Violation](super::Violation)s found, if any.
+    pub fn validate(&self) -> Vec<super::Violation> {
+        super::validation::validate(&self.headers, &self.body, None)
+    }
+
+    /// Adds the given tag to the `To` header, unless the header has a tag already or this is a
+    /// `100 Trying` response, as described in
+    /// [RFC3261 section 8.2.6.2](https://datatracker.ietf.org/doc/html/rfc3261#section-8.2.6.2).
+    pub fn with_to_tag(mut self, tag: Tag) -> Result<Self, Error> {
+        if self.status_code != StatusCode::Trying {
+            let to = self.to_header_mut()?;
+            if to.tag()?.is_none() {
+                to.mut_tag(tag)?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// All the challenges of the `WWW-Authenticate` headers, in order, where a header can have
+    /// more than one challenge. Any challenge that can't be converted to its typed form (like a
+    /// `Basic` one, which has no nonce) is skipped.
+    pub fn www_authenticate_challenges(&self) -> Vec<typed::WwwAuthenticate> {
+        self.www_authenticate_headers()
+            .into_iter()
+            .flat_map(|header| challenges(header.value()))
+            .collect()
+    }
+
+    /// Same as [www_authenticate_challenges](Response::www_authenticate_challenges) for the
+    /// `Proxy-Authenticate` headers.
+    pub fn proxy_authenticate_challenges(&self) -> Vec<typed::ProxyAuthenticate> {
+        self.proxy_authenticate_headers()
+            .into_iter()
+            .flat_map(|header| challenges(header.value()))
+            .map(typed::ProxyAuthenticate)
+            .collect()
+    }
+}
+
+fn challenges(value: &str) -> Vec<typed::WwwAuthenticate> {
+    use typed::{tokenizers::AuthTokenizer, Tokenize};
+
+    AuthTokenizer::split_challenges(value)
+        .into_iter()
+        .filter_map(|challenge| {
+            AuthTokenizer::tokenize(challenge)
+                .and_then(typed::WwwAuthenticate::try_from)
+                .ok()
+        })
+        .collect()
 }
 
 impl super::HasHeaders for Response {
@@ -59,16 +160,13 @@ impl super::HasHeaders for Response {
     }
 }
 
+/// Using the alternate flag (`{:#}`) prints the headers in their compact form, if any, see
+/// [Headers](crate::Headers).
 impl std::fmt::Display for Response {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{} {}\r\n{}\r\n{}",
-            self.version,
-            self.status_code,
-            self.headers,
-            String::from_utf8_lossy(&self.body)
-        )
+        write!(f, "{} {}\r\n", self.version, self.status_code)?;
+        self.headers.fmt(f)?;
+        write!(f, "\r\n{}", String::from_utf8_lossy(&self.body))
     }
 }
 
@@ -146,24 +244,18 @@ impl From<Response> for bytes::Bytes {
 #[doc(hidden)]
 pub mod tokenizer {
     use super::{header, status_code, version, Response};
-    use crate::{Error, IResult, NomError, TokenizerError};
+    use crate::{
+        error::{Locate, TokenizeFailure, Unlocated},
+        Component, Error, IResult, TokenizerError,
+    };
+    use nom::{bytes::complete::tag, character::complete::space1};
     use std::convert::TryInto;
 
     impl<'a> TryInto<Response> for Tokenizer<'a> {
         type Error = Error;
 
         fn try_into(self) -> Result<Response, Error> {
-            Ok(Response {
-                version: self.version.try_into()?,
-                status_code: self.status_code.try_into()?,
-                headers: self
-                    .headers
-                    .into_iter()
-                    .map(TryInto::try_into)
-                    .collect::<Result<Vec<_>, Error>>()?
-                    .into(),
-                body: self.body.into(),
-            })
+            self.convert(&Unlocated)
         }
     }
 
@@ -177,29 +269,17 @@ pub mod tokenizer {
 
     impl<'a> Tokenizer<'a> {
         pub fn tokenize(part: &'a [u8]) -> IResult<Self> {
-            use crate::parser_utils::is_empty_or_fail_with;
-            use nom::{
-                branch::alt,
-                bytes::complete::{tag, take_until},
-                character::complete::space1,
-                multi::many0,
-                sequence::tuple,
-            };
-
-            let (rem, (version, _, status_code, _)) = tuple((
-                version::Tokenizer::tokenize,
-                space1,
-                status_code::Tokenizer::tokenize,
-                tag("\r\n"),
-            ))(part)?;
-
-            let (body, (raw_headers, _)) = alt((
-                tuple((take_until("\r\n\r\n"), tag("\r\n\r\n"))),
-                tuple((take_until("\r\n"), tag("\r\n"))),
-            ))(rem)
-            .map_err(|_: NomError<'a>| TokenizerError::from(("headers", rem)).into())?;
-            let (rem, headers) = many0(header::Tokenizer::tokenize)(raw_headers)?;
-            is_empty_or_fail_with(rem, ("headers", rem))?;
+            Self::tokenize_located(part).map_err(|failure| failure.error)
+        }
+
+        //like tokenize, but on failure it also tells the part of the input that failed
+        pub(crate) fn tokenize_located(
+            part: &'a [u8],
+        ) -> Result<(&'a [u8], Self), TokenizeFailure<'a>> {
+            let (rem, (version, status_code)) = Self::tokenize_start_line(part)?;
+            let (rem, _) = tag::<_, _, TokenizerError>("\r\n")(rem)
+                .map_err(TokenizeFailure::of(rem, Component::StartLine))?;
+            let (body, headers) = header::Tokenizer::tokenize_headers(rem)?;
 
             Ok((
                 &[],
@@ -211,5 +291,50 @@ pub mod tokenizer {
                 },
             ))
         }
+
+        #[allow(clippy::type_complexity)]
+        pub(crate) fn tokenize_start_line(
+            part: &'a [u8],
+        ) -> Result<
+            (
+                &'a [u8],
+                (
+                    version::Tokenizer<'a, &'a [u8], u8>,
+                    status_code::Tokenizer<'a, &'a [u8], u8>,
+                ),
+            ),
+            TokenizeFailure<'a>,
+        > {
+            let (rem, version) = version::Tokenizer::tokenize(part)
+                .map_err(TokenizeFailure::of(part, Component::Version))?;
+            let (rem, _) = space1::<_, TokenizerError>(rem)
+                .map_err(TokenizeFailure::of(rem, Component::StartLine))?;
+            let (rem, status_code) = status_code::Tokenizer::tokenize(rem)
+                .map_err(TokenizeFailure::of(rem, Component::StatusCode))?;
+
+            Ok((rem, (version, status_code)))
+        }
+
+        pub(crate) fn convert<L: Locate>(self, locator: &L) -> Result<Response, L::Error> {
+            Ok(Response {
+                version: locator.convert(
+                    self.version.major,
+                    || Component::Version,
+                    self.version,
+                )?,
+                status_code: locator.convert(
+                    self.status_code.code,
+                    || Component::StatusCode,
+                    self.status_code,
+                )?,
+                headers: self
+                    .headers
+                    .into_iter()
+                    .map(|header| header.convert(locator))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into(),
+                body: self.body.into(),
+            })
+        }
     }
 }
@@ -2,7 +2,7 @@
 pub use tokenizer::Tokenizer;
 
 use super::{request, response};
-use crate::{common::Version, Error, Headers, Request, Response};
+use crate::{common::Version, Error, Headers, PositionedError, Request, Response};
 use std::convert::{TryFrom, TryInto};
 
 /// SipMessage reprsents a generic SIP message, which could either be a [Request](crate::Request)
@@ -20,6 +20,7 @@ use std::convert::{TryFrom, TryInto};
 /// that has implemented the [HasHeaders](crate::message::HasHeaders) trait, which SipMessage
 /// implements it.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SipMessage {
     Request(Request),
     Response(Response),
@@ -54,6 +55,36 @@ impl SipMessage {
             Self::Response(response) => response.body_mut(),
         }
     }
+
+    /// Parses a [Request] or a [Response], depending on whether the input starts with the SIP
+    /// version, returning a [PositionedError] on failure.
+    pub fn parse(input: &[u8]) -> Result<Self, PositionedError> {
+        match input.starts_with(b"SIP/") {
+            true => Ok(Self::Response(Response::parse(input)?)),
+            false => Ok(Self::Request(Request::parse(input)?)),
+        }
+    }
+
+    /// Parses a [Request] or a [Response] with the given
+    /// [ParseOptions](super::ParseOptions), like [parse](SipMessage::parse) does.
+    pub fn parse_with(
+        input: &[u8],
+        options: &super::ParseOptions,
+    ) -> Result<(Self, Vec<PositionedError>), PositionedError> {
+        match input.starts_with(b"SIP/") {
+            true => Response::parse_with(input, options)
+                .map(|(response, diagnostics)| (Self::Response(response), diagnostics)),
+            false => Request::parse_with(input, options)
+                .map(|(request, diagnostics)| (Self::Request(request), diagnostics)),
+        }
+    }
+
+    pub fn validate(&self) -> Vec<super::Violation> {
+        match self {
+            Self::Request(request) => request.validate(),
+            Self::Response(response) => response.validate(),
+        }
+    }
 }
 
 impl From<Request> for SipMessage {
@@ -87,8 +118,8 @@ impl super::HasHeaders for SipMessage {
 impl std::fmt::Display for SipMessage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Request(request) => write!(f, "{}", request),
-            Self::Response(response) => write!(f, "{}", response),
+            Self::Request(request) => request.fmt(f),
+            Self::Response(response) => response.fmt(f),
         }
     }
 }
@@ -0,0 +1,242 @@
+use super::{request, response, sip_message, HeadersRefExt};
+use crate::{
+    common::{uri, Method, StatusCode, Uri, Version},
+    headers::{Header, HeaderRef},
+    Error, Request, Response, SipMessage,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// The borrowed counterpart of [Request], holding slices of the input it was parsed from.
+///
+/// Only the method and the version are parsed upfront, the uri is parsed on access while the
+/// headers are kept as [HeaderRef]s. It can be converted to an owned [Request] using the
+/// `TryFrom` conversion.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RequestRef<'a> {
+    method: Method,
+    uri: uri::Tokenizer<'a, &'a [u8], u8>,
+    version: Version,
+    headers: Vec<HeaderRef<'a>>,
+    body: &'a [u8],
+}
+
+/// The borrowed counterpart of [Response], holding slices of the input it was parsed from.
+///
+/// Only the version and the status code are parsed upfront, while the headers are kept as
+/// [HeaderRef]s. It can be converted to an owned [Response] using the `TryFrom` conversion.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ResponseRef<'a> {
+    version: Version,
+    status_code: StatusCode,
+    headers: Vec<HeaderRef<'a>>,
+    body: &'a [u8],
+}
+
+/// The borrowed counterpart of [SipMessage], which avoids allocating a `String` for every header
+/// and a `Vec<u8>` for the body when parsing, by keeping slices of the input instead.
+///
+/// The headers can be accessed through the [HeadersRefExt] trait, while the whole message can be
+/// converted to an owned [SipMessage] using the `TryFrom` conversion, when needed:
+/// ```
+/// use rsip::message::{HeadersRefExt, SipMessageRef};
+/// use std::convert::TryFrom;
+///
+/// let input = "SIP/2.0 200 OK\r\nCall-ID: 1j9FpLxk3uxtm8tn\r\nContent-Length: 0\r\n\r\n";
+/// let message = SipMessageRef::try_from(input.as_bytes()).unwrap();
+/// assert_eq!(message.call_id_header().unwrap().value().unwrap(), "1j9FpLxk3uxtm8tn");
+///
+/// let message = rsip::SipMessage::try_from(message).unwrap();
+/// assert!(message.is_response());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SipMessageRef<'a> {
+    Request(RequestRef<'a>),
+    Response(ResponseRef<'a>),
+}
+
+impl<'a> RequestRef<'a> {
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn uri(&self) -> Result<Uri, Error> {
+        self.uri.clone().try_into()
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn body(&self) -> &'a [u8] {
+        self.body
+    }
+}
+
+impl<'a> ResponseRef<'a> {
+    pub fn status_code(&self) -> &StatusCode {
+        &self.status_code
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn body(&self) -> &'a [u8] {
+        self.body
+    }
+}
+
+impl<'a> SipMessageRef<'a> {
+    pub fn is_request(&self) -> bool {
+        matches!(self, Self::Request(_))
+    }
+
+    pub fn is_response(&self) -> bool {
+        matches!(self, Self::Response(_))
+    }
+
+    pub fn version(&self) -> &Version {
+        match self {
+            Self::Request(request) => request.version(),
+            Self::Response(response) => response.version(),
+        }
+    }
+
+    pub fn body(&self) -> &'a [u8] {
+        match self {
+            Self::Request(request) => request.body(),
+            Self::Response(response) => response.body(),
+        }
+    }
+}
+
+impl<'a> HeadersRefExt<'a> for RequestRef<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>] {
+        &self.headers
+    }
+}
+
+impl<'a> HeadersRefExt<'a> for ResponseRef<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>] {
+        &self.headers
+    }
+}
+
+impl<'a> HeadersRefExt<'a> for SipMessageRef<'a> {
+    fn headers(&self) -> &[HeaderRef<'a>] {
+        match self {
+            Self::Request(request) => request.headers(),
+            Self::Response(response) => response.headers(),
+        }
+    }
+}
+
+impl<'a> TryFrom<request::Tokenizer<'a>> for RequestRef<'a> {
+    type Error = Error;
+
+    fn try_from(tokenizer: request::Tokenizer<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            method: tokenizer.method.try_into()?,
+            uri: tokenizer.uri,
+            version: tokenizer.version.try_into()?,
+            headers: tokenizer.headers.into_iter().map(Into::into).collect(),
+            body: tokenizer.body,
+        })
+    }
+}
+
+impl<'a> TryFrom<response::Tokenizer<'a>> for ResponseRef<'a> {
+    type Error = Error;
+
+    fn try_from(tokenizer: response::Tokenizer<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: tokenizer.version.try_into()?,
+            status_code: tokenizer.status_code.try_into()?,
+            headers: tokenizer.headers.into_iter().map(Into::into).collect(),
+            body: tokenizer.body,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RequestRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a [u8]) -> Result<Self, Self::Error> {
+        request::Tokenizer::tokenize(from)?.1.try_into()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ResponseRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a [u8]) -> Result<Self, Self::Error> {
+        response::Tokenizer::tokenize(from)?.1.try_into()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SipMessageRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a [u8]) -> Result<Self, Self::Error> {
+        match sip_message::Tokenizer::tokenize(from)?.1 {
+            sip_message::Tokenizer::Request(tokenizer) => Ok(Self::Request(tokenizer.try_into()?)),
+            sip_message::Tokenizer::Response(tokenizer) => {
+                Ok(Self::Response(tokenizer.try_into()?))
+            }
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SipMessageRef<'a> {
+    type Error = Error;
+
+    fn try_from(from: &'a str) -> Result<Self, Self::Error> {
+        Self::try_from(from.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<RequestRef<'a>> for Request {
+    type Error = Error;
+
+    fn try_from(request: RequestRef<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            uri: request.uri()?,
+            method: request.method,
+            version: request.version,
+            headers: headers(request.headers)?,
+            body: request.body.into(),
+        })
+    }
+}
+
+impl<'a> TryFrom<ResponseRef<'a>> for Response {
+    type Error = Error;
+
+    fn try_from(response: ResponseRef<'a>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: response.version,
+            status_code: response.status_code,
+            headers: headers(response.headers)?,
+            body: response.body.into(),
+        })
+    }
+}
+
+impl<'a> TryFrom<SipMessageRef<'a>> for SipMessage {
+    type Error = Error;
+
+    fn try_from(message: SipMessageRef<'a>) -> Result<Self, Self::Error> {
+        match message {
+            SipMessageRef::Request(request) => Ok(Self::Request(request.try_into()?)),
+            SipMessageRef::Response(response) => Ok(Self::Response(response.try_into()?)),
+        }
+    }
+}
+
+fn headers(headers: Vec<HeaderRef>) -> Result<crate::Headers, Error> {
+    Ok(headers
+        .into_iter()
+        .map(Header::try_from)
+        .collect::<Result<Vec<_>, Error>>()?
+        .into())
+}
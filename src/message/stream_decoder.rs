@@ -0,0 +1,163 @@
+use crate::{message::HeadersExt, Error, SipMessage};
+use bytes::{Buf, BytesMut};
+use std::convert::TryFrom;
+
+/// The outcome of [StreamDecoder::decode].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Decoded {
+    Message(SipMessage),
+    Incomplete,
+}
+
+/// The default limit of the message head size of a [StreamDecoder], 64KiB.
+pub const DEFAULT_MAX_HEAD_LEN: usize = 64 * 1024;
+/// The default limit of the message body size of a [StreamDecoder], 1MiB.
+pub const DEFAULT_MAX_BODY_LEN: usize = 1024 * 1024;
+
+/// Incremental decoder for SIP messages arriving over stream transports (TCP, TLS, WebSocket
+/// etc), where a single read may hold a partial message, many messages or some keep-alives.
+///
+/// Bytes are buffered using [feed](StreamDecoder::feed) and messages are taken out using
+/// [decode](StreamDecoder::decode), which uses the `Content-Length` header (mandatory in stream
+/// transports) to find where the body ends. Any bytes after that are kept for the next message.
+/// [RFC5626](https://datatracker.ietf.org/doc/html/rfc5626#section-3.5.1) CRLF keep-alives found
+/// between messages are skipped.
+///
+/// Since the peer controls what gets buffered, the size of a message head (up to and including
+/// the empty line) and of a body are limited to [DEFAULT_MAX_HEAD_LEN] and
+/// [DEFAULT_MAX_BODY_LEN] bytes by default, which can be changed using
+/// [with_max_head_len](StreamDecoder::with_max_head_len) and
+/// [with_max_body_len](StreamDecoder::with_max_body_len). A message exceeding them fails to
+/// decode.
+///
+/// ```
+/// use rsip::message::{Decoded, StreamDecoder};
+///
+/// let mut decoder = StreamDecoder::default();
+/// decoder.feed(b"\r\n\r\nSIP/2.0 200 OK\r\nContent-Length: 4\r\n\r\nbo");
+/// assert_eq!(decoder.decode(), Ok(Decoded::Incomplete));
+///
+/// decoder.feed(b"dySIP/2.0");
+/// assert!(matches!(decoder.decode(), Ok(Decoded::Message(_))));
+/// assert_eq!(decoder.buffer(), b"SIP/2.0");
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamDecoder {
+    buffer: BytesMut,
+    //how much of the buffer has been already searched for the end of the message head
+    scanned: usize,
+    //message (without its body) and the length of its body, when the message head has been
+    //already parsed but the body has not arrived fully yet
+    pending: Option<(SipMessage, usize)>,
+    max_head_len: usize,
+    max_body_len: usize,
+}
+
+impl Default for StreamDecoder {
+    fn default() -> Self {
+        Self {
+            buffer: Default::default(),
+            scanned: 0,
+            pending: None,
+            max_head_len: DEFAULT_MAX_HEAD_LEN,
+            max_body_len: DEFAULT_MAX_BODY_LEN,
+        }
+    }
+}
+
+impl StreamDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_head_len(mut self, max_head_len: usize) -> Self {
+        self.max_head_len = max_head_len;
+        self
+    }
+
+    pub fn with_max_body_len(mut self, max_body_len: usize) -> Self {
+        self.max_body_len = max_body_len;
+        self
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes)
+    }
+
+    /// The bytes buffered so far that haven't been decoded yet.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Tries to decode the next message from the buffered bytes. On error the head of the
+    /// malformed message (up to and including the empty line) is dropped from the buffer, so
+    /// that decoding can go on with the bytes that follow it. Any body of the malformed message
+    /// can't be framed though, hence it will most probably fail to decode as well, until the
+    /// stream gets in sync again. The same goes for a message with a body longer than the
+    /// limit, while a head longer than the limit drops all the buffered bytes, as there is no
+    /// way to tell where the next message starts.
+    pub fn decode(&mut self) -> Result<Decoded, Error> {
+        if self.pending.is_none() {
+            while self.buffer.starts_with(b"\r\n") {
+                self.buffer.advance(2);
+                self.scanned = self.scanned.saturating_sub(2);
+            }
+
+            let head_len = match find_head_end(&self.buffer, self.scanned) {
+                Some(head_len) if head_len <= self.max_head_len => head_len,
+                Some(_) => return Err(self.head_too_long()),
+                None if self.buffer.len() > self.max_head_len => return Err(self.head_too_long()),
+                None => {
+                    self.scanned = self.buffer.len();
+                    return Ok(Decoded::Incomplete);
+                }
+            };
+
+            self.scanned = 0;
+            let head = self.buffer.split_to(head_len);
+            let message = SipMessage::try_from(&head[..])?;
+            let body_len = message
+                .content_length_header()
+                .ok_or_else(|| Error::missing_header("Content-Length"))?
+                .length()? as usize;
+            if body_len > self.max_body_len {
+                return Err(Error::ParseError(format!(
+                    "message body exceeds {} bytes",
+                    self.max_body_len
+                )));
+            }
+
+            self.pending = Some((message, body_len));
+        }
+
+        match self.pending.take() {
+            Some((mut message, body_len)) if self.buffer.len() >= body_len => {
+                *message.body_mut() = self.buffer.split_to(body_len).to_vec();
+                Ok(Decoded::Message(message))
+            }
+            pending => {
+                self.pending = pending;
+                Ok(Decoded::Incomplete)
+            }
+        }
+    }
+
+    fn head_too_long(&mut self) -> Error {
+        self.buffer.clear();
+        self.scanned = 0;
+
+        Error::ParseError(format!("message head exceeds {} bytes", self.max_head_len))
+    }
+}
+
+//length of the message head, including the empty line that separates it from the body,
+//searching from the given offset (minus the 3 bytes that might be the start of the empty line)
+fn find_head_end(buffer: &[u8], scanned: usize) -> Option<usize> {
+    let from = scanned.saturating_sub(3);
+
+    buffer[from..]
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .map(|position| from + position + 4)
+}
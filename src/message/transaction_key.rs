@@ -0,0 +1,133 @@
+use crate::{
+    headers::{untyped::ToTypedHeader, UntypedHeader},
+    message::HeadersExt,
+    param::Branch,
+    Error, HostWithPort, Method, Request, Response,
+};
+
+/// The branch prefix that RFC3261 compliant elements use, that makes the branch unique across
+/// space and time.
+pub const MAGIC_COOKIE: &str = "z9hG4bK";
+
+/// Key that identifies a SIP transaction, computed as described in
+/// [RFC3261 section 17](https://datatracker.ietf.org/doc/html/rfc3261#section-17), meant to be
+/// used as a `HashMap` key when matching messages to transactions.
+///
+/// * [Client](TransactionKey::Client) keys are used to match responses to client transactions
+///   ([section 17.1.3](https://datatracker.ietf.org/doc/html/rfc3261#section-17.1.3)), using the
+///   branch of the top `Via` and the `CSeq` method, so a `CANCEL` has its own transaction.
+/// * [Server](TransactionKey::Server) keys are used to match requests to server transactions
+///   ([section 17.2.3](https://datatracker.ietf.org/doc/html/rfc3261#section-17.2.3)) when the
+///   branch starts with the [MAGIC_COOKIE], using the branch and sent-by of the top `Via` and the
+///   request method, where an `ACK` matches the `INVITE` transaction.
+/// * [Rfc2543](TransactionKey::Rfc2543) keys are the server side fallback for requests coming
+///   from RFC2543 elements, which don't use the magic cookie, using the Request-URI, the `To`
+///   and `From` tags, the `Call-ID`, the `CSeq` and the top `Via` (normalized, so that it is
+///   compared case-insensitively and regardless of whitespace). The `To` tag of an `ACK` is the
+///   one of the response and not the one of the `INVITE`, so an `ACK` doesn't match the key of
+///   the `INVITE` but the key returned by
+///   [with_response_to_tag](TransactionKey::with_response_to_tag), that the server transaction
+///   needs to keep along with the key of the `INVITE` once it has sent a response.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum TransactionKey {
+    Client {
+        branch: Branch,
+        method: Method,
+    },
+    Server {
+        branch: Branch,
+        sent_by: HostWithPort,
+        method: Method,
+    },
+    Rfc2543 {
+        request_uri: String,
+        to_tag: Option<String>,
+        from_tag: Option<String>,
+        call_id: String,
+        cseq: u32,
+        method: Method,
+        via: String,
+    },
+}
+
+impl TransactionKey {
+    /// Computes the client transaction key of an outgoing request or an incoming response.
+    pub fn client(message: &impl HeadersExt) -> Result<Self, Error> {
+        Ok(Self::Client {
+            branch: message.via_header()?.branch()?,
+            method: message.cseq_header()?.method()?,
+        })
+    }
+
+    /// Computes the server transaction key of an incoming request, falling back to the RFC2543
+    /// key when the branch of the top `Via` does not start with the [MAGIC_COOKIE].
+    pub fn server(request: &Request) -> Result<Self, Error> {
+        let via = request.via_header()?.typed()?;
+        let method = match request.method {
+            Method::Ack => Method::Invite,
+            method => method,
+        };
+
+        match via.branch() {
+            Ok(branch) if branch.value().starts_with(MAGIC_COOKIE) => Ok(Self::Server {
+                branch: branch.clone(),
+                sent_by: via.sent_by().host_with_port.clone(),
+                method,
+            }),
+            _ => Ok(Self::Rfc2543 {
+                request_uri: request.uri.to_string(),
+                to_tag: request.to_header()?.tag()?.map(|tag| tag.value().into()),
+                from_tag: request.from_header()?.tag()?.map(|tag| tag.value().into()),
+                call_id: request.call_id_header()?.value().into(),
+                cseq: request.cseq_header()?.seq()?,
+                method,
+                via: via.to_string().to_ascii_lowercase(),
+            }),
+        }
+    }
+
+    /// Computes the server transaction key of an outgoing response, for looking up the server
+    /// transaction it belongs to. This works only for transactions created by requests with a
+    /// [MAGIC_COOKIE] branch, since RFC2543 keys need the Request-URI.
+    pub fn server_from_response(response: &Response) -> Result<Self, Error> {
+        let via = response.via_header()?.typed()?;
+        let branch = via.branch()?;
+
+        match branch.value().starts_with(MAGIC_COOKIE) {
+            true => Ok(Self::Server {
+                branch: branch.clone(),
+                sent_by: via.sent_by().host_with_port.clone(),
+                method: match response.cseq_header()?.method()? {
+                    Method::Ack => Method::Invite,
+                    method => method,
+                },
+            }),
+            false => Err(Error::Unexpected(
+                "can't compute an RFC2543 transaction key from a response".into(),
+            )),
+        }
+    }
+
+    /// Returns the key that the `ACK` of the given response matches, when this is the
+    /// [Rfc2543](TransactionKey::Rfc2543) key of an `INVITE`: the same key with the `To` tag of
+    /// the response. Any other key is returned as is, since it matches the `ACK` already.
+    pub fn with_response_to_tag(&self, response: &Response) -> Result<Self, Error> {
+        match self {
+            Self::Rfc2543 {
+                method: Method::Invite,
+                ..
+            } => {
+                let mut key = self.clone();
+                if let Self::Rfc2543 { to_tag, .. } = &mut key {
+                    *to_tag = response.to_header()?.tag()?.map(|tag| tag.value().into());
+                }
+                Ok(key)
+            }
+            key => Ok(key.clone()),
+        }
+    }
+
+    pub fn is_rfc2543(&self) -> bool {
+        matches!(self, Self::Rfc2543 { .. })
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    headers::{self, untyped::ToTypedHeader, Header, Headers},
+    Error, Method,
+};
+
+/// A violation of the rules of
+/// [RFC3261](https://datatracker.ietf.org/doc/html/rfc3261) that a parsed message can still have,
+/// returned by [Request::validate](crate::Request::validate) and
+/// [Response::validate](crate::Response::validate).
+///
+/// Rsip parses headers lazily, so a message with a missing or malformed header is parsed
+/// successfully, leaving it to the consumer to find out on first access. Validation checks all
+/// of them upfront:
+/// * `MissingHeader`: one of `To`, `From`, `CSeq`, `Call-ID`, `Via` and, for requests,
+///   `Max-Forwards` is missing
+///   ([section 8.1.1](https://datatracker.ietf.org/doc/html/rfc3261#section-8.1.1)).
+/// * `DuplicateHeader`: a header that can appear only once (like `To` or `Content-Length`)
+///   appears more than once.
+/// * `InvalidHeader`: a header can't be converted to its typed form (or number for
+///   `Max-Forwards`/`Content-Length`), along with the relevant error.
+/// * `CSeqMethodMismatch`: the `CSeq` method of a request is not the request method.
+/// * `MaxForwardsOutOfRange`: `Max-Forwards` is above 255.
+/// * `ContentLengthMismatch`: `Content-Length` is not the actual size of the body.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Violation {
+    MissingHeader(String),
+    DuplicateHeader(String),
+    InvalidHeader(String, Error),
+    CSeqMethodMismatch { method: Method, cseq: Method },
+    MaxForwardsOutOfRange(u32),
+    ContentLengthMismatch { content_length: u32, body: usize },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHeader(name) => write!(f, "missing header: {}", name),
+            Self::DuplicateHeader(name) => write!(f, "duplicate header: {}", name),
+            Self::InvalidHeader(name, error) => write!(f, "invalid header {}: {}", name, error),
+            Self::CSeqMethodMismatch { method, cseq } => {
+                write!(f, "CSeq method {} does not match method {}", cseq, method)
+            }
+            Self::MaxForwardsOutOfRange(max_forwards) => {
+                write!(f, "Max-Forwards {} is out of range", max_forwards)
+            }
+            Self::ContentLengthMismatch {
+                content_length,
+                body,
+            } => write!(
+                f,
+                "Content-Length {} does not match body length {}",
+                content_length, body
+            ),
+        }
+    }
+}
+
+macro_rules! typed_error {
+    ($header:expr, $($variant:ident),*) => {
+        match $header {
+            $(Header::$variant(header) => header.typed().err(),)*
+            Header::MaxForwards(header) => header.num().err(),
+            Header::ContentLength(header) => header.length().err(),
+            _ => None,
+        }
+    };
+}
+
+// `method` is the method of the request, `None` for responses
+pub(crate) fn validate(headers: &Headers, body: &[u8], method: Option<Method>) -> Vec<Violation> {
+    let mut mandatory = vec![
+        headers::To::NAME,
+        headers::From::NAME,
+        headers::CSeq::NAME,
+        headers::CallId::NAME,
+        headers::Via::NAME,
+    ];
+    if method.is_some() {
+        mandatory.push(headers::MaxForwards::NAME);
+    }
+
+    let mut violations = mandatory
+        .into_iter()
+        .filter(|mandatory| {
+            !headers
+                .iter()
+                .any(|header| header.name() == Some(mandatory))
+        })
+        .map(|name| Violation::MissingHeader(name.into()))
+        .collect::<Vec<_>>();
+
+    let kind = std::mem::discriminant::<Header>;
+    let duplicates = headers
+        .iter()
+        .filter(|header| is_single_instance(header))
+        .filter(|header| {
+            headers
+                .iter()
+                .filter(|other| kind(other) == kind(header))
+                .count()
+                > 1
+        })
+        .fold(vec![], |mut duplicates: Vec<&Header>, header| {
+            if !duplicates.iter().any(|other| kind(other) == kind(header)) {
+                duplicates.push(header);
+            }
+            duplicates
+        });
+    violations.extend(
+        duplicates
+            .into_iter()
+            .map(|header| Violation::DuplicateHeader(name_of(header))),
+    );
+
+    for header in headers.iter() {
+        let error = typed_error!(
+            header,
+            Accept,
+            AlertInfo,
+            Allow,
+            AuthenticationInfo,
+            Authorization,
+            CSeq,
+            CallInfo,
+            Contact,
+            ContentDisposition,
+            ContentType,
+            ErrorInfo,
+            From,
+            InReplyTo,
+            Priority,
+            ProxyAuthenticate,
+            ProxyAuthorization,
+            RecordRoute,
+            ReplyTo,
+            Route,
+            To,
+            Via,
+            Warning,
+            WwwAuthenticate
+        );
+
+        if let Some(error) = error {
+            violations.push(Violation::InvalidHeader(name_of(header), error));
+            continue;
+        }
+
+        match header {
+            Header::CSeq(cseq) => match (method, cseq.method()) {
+                (Some(method), Ok(cseq)) if method != cseq => {
+                    violations.push(Violation::CSeqMethodMismatch { method, cseq })
+                }
+                _ => (),
+            },
+            Header::MaxForwards(max_forwards) => match max_forwards.num() {
+                Ok(max_forwards) if max_forwards > 255 => {
+                    violations.push(Violation::MaxForwardsOutOfRange(max_forwards))
+                }
+                _ => (),
+            },
+            Header::ContentLength(content_length) => match content_length.length() {
+                Ok(content_length) if content_length as usize != body.len() => {
+                    violations.push(Violation::ContentLengthMismatch {
+                        content_length,
+                        body: body.len(),
+                    })
+                }
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    violations
+}
+
+fn is_single_instance(header: &Header) -> bool {
+    matches!(
+        header,
+        Header::To(_)
+            | Header::From(_)
+            | Header::CallId(_)
+            | Header::CSeq(_)
+            | Header::MaxForwards(_)
+            | Header::ContentLength(_)
+            | Header::ContentType(_)
+            | Header::Expires(_)
+            | Header::MinExpires(_)
+    )
+}
+
+//only headers with a known name are checked
+fn name_of(header: &Header) -> String {
+    header.name().unwrap_or_default().into()
+}
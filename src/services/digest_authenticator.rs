@@ -0,0 +1,327 @@
+use crate::{
+    headers::{
+        auth::{Algorithm, AuthQop, Qop, Scheme},
+        typed,
+        untyped::ToTypedHeader,
+        Header,
+    },
+    services::DigestGenerator,
+    Request, Response, StatusCode,
+};
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The credential of a user, as returned by a [CredentialLookup]: either the plain password or
+/// the precomputed `HA1`, the hash of `username:realm:password` using the algorithm of the
+/// [DigestAuthenticator].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Credential {
+    Password(String),
+    Ha1(String),
+}
+
+/// Looks up the [Credential] of a user in a realm, used by the [DigestAuthenticator] to verify
+/// the `Authorization` headers of the incoming requests. It is implemented for any
+/// `Fn(&str, &str) -> Option<Credential>` that takes the username and the realm.
+pub trait CredentialLookup {
+    fn lookup(&self, username: &str, realm: &str) -> Option<Credential>;
+}
+
+impl<F> CredentialLookup for F
+where
+    F: Fn(&str, &str) -> Option<Credential>,
+{
+    fn lookup(&self, username: &str, realm: &str) -> Option<Credential> {
+        self(username, realm)
+    }
+}
+
+/// The reason an `Authorization` header was rejected by the [DigestAuthenticator]:
+/// * `Missing`: there is no `Authorization` (or `Proxy-Authorization`) header for the realm.
+/// * `Invalid`: the header can't be converted to its typed form, its scheme or algorithm is not
+///   the one of the authenticator, its qop is not one the authenticator asks for, or its uri
+///   doesn't match the Request-URI.
+/// * `UnknownUser`: the [CredentialLookup] has no credential for the user.
+/// * `InvalidNonce`: the nonce was not issued by the authenticator.
+/// * `Stale`: the nonce has expired, while the response is otherwise valid, so the client should
+///   retry with a new nonce without asking the user for the password again.
+/// * `Replay`: the nonce count was already used with the nonce.
+/// * `InvalidResponse`: the response doesn't match, most probably due to a wrong password.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rejection {
+    Missing,
+    Invalid,
+    UnknownUser,
+    InvalidNonce,
+    Stale,
+    Replay,
+    InvalidResponse,
+}
+
+impl std::fmt::Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing authorization"),
+            Self::Invalid => write!(f, "invalid authorization"),
+            Self::UnknownUser => write!(f, "unknown user"),
+            Self::InvalidNonce => write!(f, "invalid nonce"),
+            Self::Stale => write!(f, "stale nonce"),
+            Self::Replay => write!(f, "replayed nonce count"),
+            Self::InvalidResponse => write!(f, "invalid response"),
+        }
+    }
+}
+
+/// The server side of the `Digest` authentication, as used by a registrar (or a proxy, through
+/// [proxy](DigestAuthenticator::proxy)): it issues the challenges and verifies the
+/// `Authorization` (or `Proxy-Authorization`) headers of the incoming requests.
+///
+/// Nonces are stateless: a nonce encodes the time it was issued at, signed with an HMAC-SHA256
+/// of the secret of the authenticator, so that any nonce can be checked without keeping it
+/// around. A nonce is valid for `nonce_lifetime`, after which a request using it is challenged
+/// again with `stale=true`. In order to detect replays, the authenticator keeps the last nonce
+/// count of every nonce in use, until the nonce expires. Nonce counts only exist along with a
+/// qop, so an authenticator without any qop (`with_qop(vec![])`) can't detect replays: any
+/// request reusing a valid `Authorization` is accepted until its nonce expires.
+///
+/// Like the transaction state machines, the authenticator doesn't read any clock, the current
+/// time is given to it instead.
+#[derive(Debug, Clone)]
+pub struct DigestAuthenticator<C: CredentialLookup> {
+    pub realm: String,
+    pub algorithm: Algorithm,
+    pub qop: Vec<Qop>,
+    pub nonce_lifetime: Duration,
+    pub proxy: bool,
+    secret: Vec<u8>,
+    credentials: C,
+    nonce_counts: HashMap<String, (u32, u64)>,
+}
+
+impl<C: CredentialLookup> DigestAuthenticator<C> {
+    /// Creates an authenticator for the given realm, issuing `MD5` challenges with `qop=auth`
+    /// and nonces valid for 5 minutes.
+    pub fn new(realm: impl Into<String>, secret: impl Into<Vec<u8>>, credentials: C) -> Self {
+        Self {
+            realm: realm.into(),
+            algorithm: Algorithm::Md5,
+            qop: vec![Qop::Auth],
+            nonce_lifetime: Duration::from_secs(300),
+            proxy: false,
+            secret: secret.into(),
+            credentials,
+            nonce_counts: Default::default(),
+        }
+    }
+
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets the qop values of the challenges. An empty list disables the replay detection,
+    /// since requests without a qop carry no nonce count.
+    pub fn with_qop(mut self, qop: Vec<Qop>) -> Self {
+        self.qop = qop;
+        self
+    }
+
+    pub fn with_nonce_lifetime(mut self, nonce_lifetime: Duration) -> Self {
+        self.nonce_lifetime = nonce_lifetime;
+        self
+    }
+
+    /// Makes the authenticator challenge with `407 Proxy Authentication Required` and
+    /// `Proxy-Authenticate`, verifying the `Proxy-Authorization` headers instead.
+    pub fn proxy(mut self) -> Self {
+        self.proxy = true;
+        self
+    }
+
+    /// Creates a new challenge, with a nonce issued at the given time.
+    pub fn challenge(&self, stale: bool, now: SystemTime) -> typed::WwwAuthenticate {
+        typed::WwwAuthenticate {
+            scheme: Scheme::Digest,
+            realm: self.realm.clone(),
+            nonce: self.nonce(now),
+            stale: match stale {
+                true => Some("true".into()),
+                false => None,
+            },
+            algorithm: Some(self.algorithm),
+            qop: self.qop.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Authenticates the given request, returning the username on success. Otherwise the
+    /// `401` (or `407`) response to send back is returned, with a new challenge, which is marked
+    /// as stale if the request was rejected due to an expired nonce.
+    #[allow(clippy::result_large_err)]
+    pub fn authenticate(&mut self, request: &Request, now: SystemTime) -> Result<String, Response> {
+        self.verify(request, now).map_err(|rejection| {
+            let challenge = self.challenge(rejection == Rejection::Stale, now);
+
+            match self.proxy {
+                true => {
+                    let mut response = request.response(StatusCode::ProxyAuthenticationRequired);
+                    response
+                        .headers
+                        .push(typed::ProxyAuthenticate(challenge).into());
+                    response
+                }
+                false => {
+                    let mut response = request.response(StatusCode::Unauthorized);
+                    response.headers.push(challenge.into());
+                    response
+                }
+            }
+        })
+    }
+
+    /// Verifies the `Authorization` (or `Proxy-Authorization`) header of the given request for
+    /// the realm of the authenticator, returning the username on success. As required by
+    /// [RFC3261 section 22.4](https://datatracker.ietf.org/doc/html/rfc3261#section-22.4), the
+    /// uri of the header must match the Request-URI (see [Uri::rfc_eq](crate::Uri::rfc_eq)).
+    pub fn verify(&mut self, request: &Request, now: SystemTime) -> Result<String, Rejection> {
+        let authorization = self.authorization(request)?;
+        if authorization.scheme != Scheme::Digest
+            || authorization.algorithm.unwrap_or(Algorithm::Md5) != self.algorithm
+            || !self.accepts(authorization.qop.as_ref())
+            || !authorization.uri.rfc_eq(&request.uri)
+        {
+            return Err(Rejection::Invalid);
+        }
+
+        let issued_at = self
+            .issued_at(&authorization.nonce)
+            .ok_or(Rejection::InvalidNonce)?;
+
+        let credential = self
+            .credentials
+            .lookup(&authorization.username, &self.realm)
+            .ok_or(Rejection::UnknownUser)?;
+        let generator = DigestGenerator {
+            username: &authorization.username,
+            password: "",
+            nonce: &authorization.nonce,
+            uri: &authorization.uri,
+            realm: &authorization.realm,
+            method: &request.method,
+            qop: authorization.qop.as_ref(),
+            algorithm: self.algorithm,
+            body: &request.body,
+        };
+        let expected = match &credential {
+            Credential::Password(password) => DigestGenerator {
+                password,
+                ..generator
+            }
+            .compute(),
+            Credential::Ha1(ha1) => generator.compute_with_ha1(ha1),
+        };
+        if !constant_time_eq(&expected, &authorization.response) {
+            return Err(Rejection::InvalidResponse);
+        }
+
+        let now = secs(now);
+        if now.saturating_sub(issued_at) > self.nonce_lifetime.as_secs() {
+            return Err(Rejection::Stale);
+        }
+
+        let lifetime = self.nonce_lifetime.as_secs();
+        self.nonce_counts
+            .retain(|_, (_, issued_at)| now.saturating_sub(*issued_at) <= lifetime);
+        if let Some(AuthQop::Auth { nc, .. } | AuthQop::AuthInt { nc, .. }) = &authorization.qop {
+            let last = self
+                .nonce_counts
+                .entry(authorization.nonce.clone())
+                .or_insert((0, issued_at));
+            if *nc <= last.0 {
+                return Err(Rejection::Replay);
+            }
+            last.0 = *nc;
+        }
+
+        Ok(authorization.username)
+    }
+
+    //any qop offered, or none if no qop is offered
+    fn accepts(&self, qop: Option<&AuthQop>) -> bool {
+        match qop {
+            Some(AuthQop::Auth { .. }) => self.qop.contains(&Qop::Auth),
+            Some(AuthQop::AuthInt { .. }) => self.qop.contains(&Qop::AuthInt),
+            None => self.qop.is_empty(),
+        }
+    }
+
+    fn authorization(&self, request: &Request) -> Result<typed::Authorization, Rejection> {
+        let mut invalid = false;
+
+        let authorization = request
+            .headers
+            .iter()
+            .filter_map(|header| match (self.proxy, header) {
+                (false, Header::Authorization(header)) => Some(header.typed()),
+                (true, Header::ProxyAuthorization(header)) => Some(header.typed().map(|h| h.0)),
+                _ => None,
+            })
+            .filter_map(|authorization| {
+                invalid |= authorization.is_err();
+                authorization.ok()
+            })
+            .find(|authorization| authorization.realm == self.realm);
+
+        match (authorization, invalid) {
+            (Some(authorization), _) => Ok(authorization),
+            (None, true) => Err(Rejection::Invalid),
+            (None, false) => Err(Rejection::Missing),
+        }
+    }
+
+    //a nonce is the hex timestamp it was issued at and a random salt, followed by their HMAC
+    fn nonce(&self, now: SystemTime) -> String {
+        let data = format!("{:x}.{}", secs(now), uuid::Uuid::new_v4().to_simple());
+
+        format!("{}.{}", data, self.mac(&data))
+    }
+
+    fn issued_at(&self, nonce: &str) -> Option<u64> {
+        let (data, mac) = nonce.rsplit_once('.')?;
+        if !constant_time_eq(&self.mac(data), mac) {
+            return None;
+        }
+
+        let (timestamp, _) = data.split_once('.')?;
+        u64::from_str_radix(timestamp, 16).ok()
+    }
+
+    fn mac(&self, data: &str) -> String {
+        use hmac::{Hmac, Mac, NewMac};
+        use sha2::Sha256;
+
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC can take a key of any size");
+        mac.update(self.realm.as_bytes());
+        mac.update(b":");
+        mac.update(data.as_bytes());
+
+        format!("{:x}", mac.finalize().into_bytes())
+    }
+}
+
+fn secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
@@ -0,0 +1,216 @@
+use crate::{
+    common::uri::{param::Branch, Param},
+    headers::{
+        auth::{Algorithm, AuthQop, Qop, Scheme},
+        typed,
+        untyped::ToTypedHeader,
+        Header,
+    },
+    message::HeadersExt,
+    services::DigestGenerator,
+    Error, Request, Response, StatusCode,
+};
+use std::collections::HashMap;
+
+/// The client side of the `Digest` authentication: it responds to the challenges of a
+/// `401 Unauthorized` or a `407 Proxy Authentication Required` response, as described in
+/// [RFC3261 section 22.2](https://datatracker.ietf.org/doc/html/rfc3261#section-22.2).
+///
+/// Out of all the `WWW-Authenticate` (or `Proxy-Authenticate`) challenges of the response, the
+/// one picked is the one whose algorithm comes first in the `algorithms` of the client (see
+/// [select_challenge]), which by default are ordered from the strongest to the weakest. If the
+/// challenge offers more than one qop, `auth` is preferred over `auth-int`. If the challenge asks
+/// for `userhash=true`, the hashed username is sent instead of the username. The client generates
+/// a new cnonce for every request and keeps the nonce count of every nonce it has responded to, so
+/// that it can keep using a nonce in subsequent requests.
+/// ```
+/// use rsip::services::DigestClient;
+/// use std::convert::TryFrom;
+///
+/// let register = rsip::Request::try_from(concat!(
+///     "REGISTER sip:biloxi.com SIP/2.0\r\n",
+///     "Via: SIP/2.0/UDP bobspc.biloxi.com:5060;branch=z9hG4bKnashds7\r\n",
+///     "To: Bob <sip:bob@biloxi.com>\r\n",
+///     "From: Bob <sip:bob@biloxi.com>;tag=456248\r\n",
+///     "Call-ID: 843817637684230@998sdasdh09\r\n",
+///     "CSeq: 1826 REGISTER\r\n\r\n"
+/// )).unwrap();
+/// let mut unauthorized = register.response(rsip::StatusCode::Unauthorized);
+/// unauthorized.headers.push(rsip::Header::WwwAuthenticate(
+///     "Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", qop=\"auth\"".into(),
+/// ));
+///
+/// let mut client = DigestClient::new("bob", "zanzibar");
+/// let register = client.authorize(&register, &unauthorized).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DigestClient {
+    pub username: String,
+    pub password: String,
+    pub algorithms: Vec<Algorithm>,
+    nonce_counts: HashMap<String, u32>,
+}
+
+impl DigestClient {
+    /// Creates a client that supports all the algorithms of [Algorithm], preferring the
+    /// RFC7616 ones over the non standard SHA-512 ones, and the plain ones over the `-sess` ones.
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+            algorithms: vec![
+                Algorithm::Sha512256,
+                Algorithm::Sha512256Sess,
+                Algorithm::Sha256,
+                Algorithm::Sha256Sess,
+                Algorithm::Sha512,
+                Algorithm::Sha512Sess,
+                Algorithm::Md5,
+                Algorithm::Md5Sess,
+            ],
+            nonce_counts: Default::default(),
+        }
+    }
+
+    pub fn with_algorithms(mut self, algorithms: Vec<Algorithm>) -> Self {
+        self.algorithms = algorithms;
+        self
+    }
+
+    /// Picks the challenge this client is going to respond to, out of the challenges of the
+    /// given `401` or `407` response.
+    pub fn challenge(&self, response: &Response) -> Result<typed::WwwAuthenticate, Error> {
+        let challenges: Vec<typed::WwwAuthenticate> = match response.status_code() {
+            StatusCode::Unauthorized => response.www_authenticate_challenges(),
+            StatusCode::ProxyAuthenticationRequired => response
+                .proxy_authenticate_challenges()
+                .into_iter()
+                .map(|challenge| challenge.0)
+                .collect(),
+            _ => {
+                return Err(Error::Unexpected(
+                    "response is not a 401 or 407 challenge".into(),
+                ))
+            }
+        };
+
+        select_challenge(&challenges, &self.algorithms)
+            .cloned()
+            .ok_or_else(|| Error::Unexpected("no supported Digest challenge found".into()))
+    }
+
+    /// Creates the request to retry, out of the given request that was challenged by the given
+    /// `401` or `407` response. The retried request has an `Authorization` (or
+    /// `Proxy-Authorization`) header responding to the [challenge](DigestClient::challenge),
+    /// replacing any previous one for the same realm, along with an incremented `CSeq` and a new
+    /// branch in its top `Via`, since it's a new transaction.
+    pub fn authorize(&mut self, request: &Request, response: &Response) -> Result<Request, Error> {
+        let challenge = self.challenge(response)?;
+        let qop = match challenge.qop.iter().min_by_key(|qop| **qop != Qop::Auth) {
+            Some(qop) => {
+                let cnonce = uuid::Uuid::new_v4().to_simple().to_string();
+                let nc = self.next_nonce_count(&challenge.nonce)?;
+
+                Some(match qop {
+                    Qop::Auth => AuthQop::Auth { cnonce, nc },
+                    Qop::AuthInt => AuthQop::AuthInt { cnonce, nc },
+                })
+            }
+            None => None,
+        };
+
+        let generator = DigestGenerator {
+            username: &self.username,
+            password: &self.password,
+            nonce: &challenge.nonce,
+            uri: &request.uri,
+            realm: &challenge.realm,
+            method: &request.method,
+            qop: qop.as_ref(),
+            algorithm: algorithm_of(&challenge),
+            body: &request.body,
+        };
+        let authorization = typed::Authorization {
+            scheme: Scheme::Digest,
+            username: match challenge.userhash {
+                Some(true) => generator.userhash(),
+                _ => self.username.clone(),
+            },
+            realm: challenge.realm.clone(),
+            nonce: challenge.nonce.clone(),
+            uri: request.uri.clone(),
+            response: generator.compute(),
+            algorithm: challenge.algorithm,
+            opaque: challenge.opaque.clone(),
+            qop,
+            userhash: challenge.userhash.filter(|userhash| *userhash),
+        };
+
+        let mut request = request.clone();
+        let realm = Some(challenge.realm.clone());
+        match response.status_code() {
+            StatusCode::ProxyAuthenticationRequired => {
+                request.headers.retain(|header| match header {
+                    Header::ProxyAuthorization(header) => {
+                        realm_of(header.typed().map(|h| h.0)) != realm
+                    }
+                    _ => true,
+                });
+                request
+                    .headers
+                    .push(typed::ProxyAuthorization(authorization).into());
+            }
+            _ => {
+                request.headers.retain(|header| match header {
+                    Header::Authorization(header) => realm_of(header.typed()) != realm,
+                    _ => true,
+                });
+                request.headers.push(authorization.into());
+            }
+        }
+
+        let seq = request.cseq_header()?.seq()?;
+        request.cseq_header_mut()?.mut_seq(seq + 1)?;
+
+        let mut via = request.via_header()?.typed()?;
+        via.params
+            .retain(|param| !matches!(param, Param::Branch(_)));
+        via.params.insert(0, Param::Branch(Branch::default()));
+        *request.via_header_mut()? = via.into();
+
+        Ok(request)
+    }
+
+    fn next_nonce_count(&mut self, nonce: &str) -> Result<u32, Error> {
+        let nc = self.nonce_counts.entry(nonce.into()).or_default();
+        *nc = nc
+            .checked_add(1)
+            .ok_or_else(|| Error::Unexpected("nonce count exhausted".into()))?;
+
+        Ok(*nc)
+    }
+}
+
+/// Selects the preferred `Digest` challenge out of the given ones, which is the first challenge
+/// with the first of the given algorithms that any challenge has, where a challenge without an
+/// algorithm is considered `MD5`. The challenges of a [Response] can be found through
+/// [Response::www_authenticate_challenges] and [Response::proxy_authenticate_challenges].
+pub fn select_challenge<'a>(
+    challenges: &'a [typed::WwwAuthenticate],
+    algorithms: &[Algorithm],
+) -> Option<&'a typed::WwwAuthenticate> {
+    algorithms.iter().find_map(|algorithm| {
+        challenges.iter().find(|challenge| {
+            challenge.scheme == Scheme::Digest && algorithm_of(challenge) == *algorithm
+        })
+    })
+}
+
+//a challenge without an algorithm is considered MD5, as in RFC2617
+fn algorithm_of(challenge: &typed::WwwAuthenticate) -> Algorithm {
+    challenge.algorithm.unwrap_or(Algorithm::Md5)
+}
+
+fn realm_of(authorization: Result<typed::Authorization, Error>) -> Option<String> {
+    authorization.ok().map(|authorization| authorization.realm)
+}
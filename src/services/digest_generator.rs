@@ -11,7 +11,8 @@ use crate::{
 /// an `&str` for the password and a [Method](crate::Method) using the [from](From::from()) method.
 ///
 /// Supports SIP versions of [RFC7616](https://datatracker.ietf.org/doc/html/rfc7616)
-/// and [RFC2617](https://datatracker.ietf.org/doc/html/rfc2617).
+/// and [RFC2617](https://datatracker.ietf.org/doc/html/rfc2617), including the `-sess`
+/// algorithms and the `auth-int` qop, which hashes the `body` of the message.
 #[derive(Debug, Clone)]
 pub struct DigestGenerator<'a> {
     pub username: &'a str,
@@ -22,6 +23,7 @@ pub struct DigestGenerator<'a> {
     pub method: &'a Method,
     pub qop: Option<&'a AuthQop>,
     pub algorithm: Algorithm,
+    pub body: &'a [u8],
 }
 
 impl<'a> DigestGenerator<'a> {
@@ -40,18 +42,49 @@ impl<'a> DigestGenerator<'a> {
             method,
             qop: auth.qop.as_ref(),
             algorithm: auth.algorithm.unwrap_or(Algorithm::Md5),
+            body: &[],
         }
     }
 
+    /// Sets the body of the message, needed only for the `auth-int` qop.
+    pub fn with_body(mut self, body: &'a [u8]) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// The hashed username, `H(username:realm)`, sent in place of the username when the
+    /// challenge asks for `userhash=true`. Note that in that case the generator still needs the
+    /// actual username, thus it can't be created [from](DigestGenerator::from) the
+    /// `Authorization` header as is.
+    pub fn userhash(&self) -> String {
+        self.hash_value(format!("{}:{}", self.username, self.realm))
+    }
+
     pub fn verify(&self, response: &'a str) -> bool {
         self.compute() == response
     }
 
     pub fn compute(&self) -> String {
+        let ha1 = self.hash_value(format!(
+            "{}:{}:{}",
+            self.username, self.realm, self.password
+        ));
+
+        self.response(self.session_ha1(ha1))
+    }
+
+    /// Same as [compute](DigestGenerator::compute), but using the given (precomputed) `HA1`,
+    /// the hash of `username:realm:password`, instead of the password. For the `-sess`
+    /// algorithms, the session `HA1` is still computed out of it.
+    pub fn compute_with_ha1(&self, ha1: &str) -> String {
+        self.response(self.session_ha1(ha1.into()))
+    }
+
+    fn response(&self, ha1: String) -> String {
         let value = match self.qop {
             Some(AuthQop::Auth { cnonce, nc }) => format!(
-                "{}:{}:{:08}:{}:{}:{}",
-                self.ha1(),
+                "{}:{}:{:08x}:{}:{}:{}",
+                ha1,
                 self.nonce,
                 nc,
                 cnonce,
@@ -59,57 +92,66 @@ impl<'a> DigestGenerator<'a> {
                 self.ha2()
             ),
             Some(AuthQop::AuthInt { cnonce, nc }) => format!(
-                "{}:{}:{:08}:{}:{}:{}",
-                self.ha1(),
+                "{}:{}:{:08x}:{}:{}:{}",
+                ha1,
                 self.nonce,
                 nc,
                 cnonce,
                 "auth-int",
                 self.ha2()
             ),
-            None => format!("{}:{}:{}", self.ha1(), self.nonce, self.ha2()),
+            None => format!("{}:{}:{}", ha1, self.nonce, self.ha2()),
         };
 
         self.hash_value(value)
     }
 
-    fn ha1(&self) -> String {
-        let value = format!("{}:{}:{}", self.username, self.realm, self.password);
+    //for the -sess algorithms HA1 is H(H(username:realm:password):nonce:cnonce)
+    fn session_ha1(&self, ha1: String) -> String {
+        let cnonce = match self.qop {
+            Some(AuthQop::Auth { cnonce, .. }) | Some(AuthQop::AuthInt { cnonce, .. }) => cnonce,
+            None => "",
+        };
 
-        self.hash_value(value)
+        match self.algorithm {
+            Algorithm::Md5Sess
+            | Algorithm::Sha256Sess
+            | Algorithm::Sha512256Sess
+            | Algorithm::Sha512Sess => {
+                self.hash_value(format!("{}:{}:{}", ha1, self.nonce, cnonce))
+            }
+            Algorithm::Md5 | Algorithm::Sha256 | Algorithm::Sha512256 | Algorithm::Sha512 => ha1,
+        }
     }
 
     fn ha2(&self) -> String {
         let value = match self.qop {
             None | Some(AuthQop::Auth { .. }) => format!("{}:{}", self.method, self.uri),
-            _ => format!(
-                "{}:{}:d41d8cd98f00b204e9800998ecf8427e",
-                self.method, self.uri
+            Some(AuthQop::AuthInt { .. }) => format!(
+                "{}:{}:{}",
+                self.method,
+                self.uri,
+                self.hash_value(self.body)
             ),
         };
 
         self.hash_value(value)
     }
 
-    fn hash_value(&self, value: String) -> String {
+    fn hash_value(&self, value: impl AsRef<[u8]>) -> String {
         use md5::{Digest, Md5};
-        use sha2::{Sha256, Sha512};
+        use sha2::{Sha256, Sha512, Sha512Trunc256};
 
         match self.algorithm {
-            Algorithm::Md5 | Algorithm::Md5Sess => {
-                let mut hasher = Md5::new();
-                hasher.update(value);
-                format!("{:x}", hasher.finalize())
-            }
+            Algorithm::Md5 | Algorithm::Md5Sess => format!("{:x}", Md5::digest(value.as_ref())),
             Algorithm::Sha256 | Algorithm::Sha256Sess => {
-                let mut hasher = Sha256::new();
-                hasher.update(value);
-                format!("{:x}", hasher.finalize())
+                format!("{:x}", Sha256::digest(value.as_ref()))
+            }
+            Algorithm::Sha512256 | Algorithm::Sha512256Sess => {
+                format!("{:x}", Sha512Trunc256::digest(value.as_ref()))
             }
             Algorithm::Sha512 | Algorithm::Sha512Sess => {
-                let mut hasher = Sha512::new();
-                hasher.update(value);
-                format!("{:x}", hasher.finalize())
+                format!("{:x}", Sha512::digest(value.as_ref()))
             }
         }
     }
@@ -1,7 +1,13 @@
 //! A module containing helpful services that go beyond headers and messages.
 //!
 
+#[doc(hidden)]
+pub mod digest_authenticator;
+#[doc(hidden)]
+pub mod digest_client;
 #[doc(hidden)]
 pub mod digest_generator;
 
+pub use digest_authenticator::{Credential, CredentialLookup, DigestAuthenticator, Rejection};
+pub use digest_client::{select_challenge, DigestClient};
 pub use digest_generator::DigestGenerator;
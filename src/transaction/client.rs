@@ -0,0 +1,214 @@
+use super::{fail, is_reliable, terminate, wait, Action, State, Timer, TimerConfig};
+use crate::{message::TransactionKey, Error, Method, Request, Response, StatusCodeKind};
+use std::time::Duration;
+
+/// The INVITE
+/// ([section 17.1.1](https://datatracker.ietf.org/doc/html/rfc3261#section-17.1.1)) and
+/// non-INVITE ([section 17.1.2](https://datatracker.ietf.org/doc/html/rfc3261#section-17.1.2))
+/// client transaction, depending on the method of the request.
+///
+/// [start](ClientTransaction::start) sends the request, then any response matching the
+/// transaction [key](ClientTransaction::key) should be fed through
+/// [on_response](ClientTransaction::on_response). A 2xx response to an INVITE moves the
+/// transaction to the `Accepted` state, where any 2xx retransmission is passed to the TU (which is
+/// responsible for the `ACK`), while the `ACK` of a non-2xx response is generated and
+/// retransmitted by the transaction itself.
+#[derive(Debug, Clone)]
+pub struct ClientTransaction {
+    key: TransactionKey,
+    request: Request,
+    state: State,
+    reliable: bool,
+    timers: TimerConfig,
+    interval: Duration,
+    ack: Option<Request>,
+}
+
+impl ClientTransaction {
+    pub fn new(request: Request) -> Result<Self, Error> {
+        let timers = TimerConfig::default();
+
+        Ok(Self {
+            key: TransactionKey::client(&request)?,
+            reliable: is_reliable(&request)?,
+            state: match request.method {
+                Method::Invite => State::Calling,
+                _ => State::Trying,
+            },
+            request,
+            timers,
+            interval: timers.t1,
+            ack: None,
+        })
+    }
+
+    pub fn with_timers(mut self, timers: TimerConfig) -> Self {
+        self.timers = timers;
+        self.interval = timers.t1;
+        self
+    }
+
+    pub fn key(&self) -> &TransactionKey {
+        &self.key
+    }
+
+    pub fn request(&self) -> &Request {
+        &self.request
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_invite(&self) -> bool {
+        self.request.method == Method::Invite
+    }
+
+    /// Sends the request and starts the timers.
+    pub fn start(&mut self) -> Vec<Action> {
+        let (retransmit, timeout) = self.timers_of_state();
+
+        let mut actions = vec![
+            Action::Send(self.request.clone().into()),
+            Action::StartTimer(timeout, self.timers.t1 * 64),
+        ];
+        if !self.reliable {
+            actions.push(Action::StartTimer(retransmit, self.interval));
+        }
+
+        actions
+    }
+
+    pub fn on_response(&mut self, response: Response) -> Result<Vec<Action>, Error> {
+        match self.is_invite() {
+            true => self.on_invite_response(response),
+            false => Ok(self.on_non_invite_response(response)),
+        }
+    }
+
+    pub fn on_timer(&mut self, timer: Timer) -> Vec<Action> {
+        match (timer, self.state) {
+            (Timer::A, State::Calling) => {
+                self.interval *= 2;
+                self.retransmit(Timer::A)
+            }
+            (Timer::E, State::Trying) => {
+                self.interval = std::cmp::min(self.interval * 2, self.timers.t2);
+                self.retransmit(Timer::E)
+            }
+            (Timer::E, State::Proceeding) => {
+                self.interval = self.timers.t2;
+                self.retransmit(Timer::E)
+            }
+            (Timer::B, State::Calling) | (Timer::F, State::Trying | State::Proceeding) => {
+                fail(&mut self.state, Action::Timeout)
+            }
+            (Timer::D, State::Completed)
+            | (Timer::K, State::Completed)
+            | (Timer::M, State::Accepted) => terminate(&mut self.state),
+            _ => vec![],
+        }
+    }
+
+    pub fn on_transport_error(&mut self) -> Vec<Action> {
+        fail(&mut self.state, Action::TransportError)
+    }
+
+    fn on_invite_response(&mut self, response: Response) -> Result<Vec<Action>, Error> {
+        let kind = response.status_code.kind();
+
+        match (self.state, kind) {
+            (State::Calling | State::Proceeding, StatusCodeKind::Provisional) => {
+                let mut actions = self.stop_timers();
+                self.state = State::Proceeding;
+                actions.push(Action::Deliver(response.into()));
+                Ok(actions)
+            }
+            (State::Calling | State::Proceeding, StatusCodeKind::Successful) => {
+                let mut actions = self.stop_timers();
+                self.state = State::Accepted;
+                actions.push(Action::Deliver(response.into()));
+                actions.push(Action::StartTimer(Timer::M, self.timers.t1 * 64));
+                Ok(actions)
+            }
+            (State::Accepted, StatusCodeKind::Successful) => {
+                Ok(vec![Action::Deliver(response.into())])
+            }
+            (State::Calling | State::Proceeding, _) => {
+                let ack = self.request.ack(&response)?;
+                let mut actions = self.stop_timers();
+                self.state = State::Completed;
+                actions.push(Action::Deliver(response.into()));
+                actions.push(Action::Send(ack.clone().into()));
+                actions.extend(wait(
+                    self.reliable,
+                    &mut self.state,
+                    Timer::D,
+                    self.timers.timer_d,
+                ));
+                self.ack = Some(ack);
+                Ok(actions)
+            }
+            (State::Completed, StatusCodeKind::Provisional | StatusCodeKind::Successful) => {
+                Ok(vec![])
+            }
+            (State::Completed, _) => Ok(self
+                .ack
+                .iter()
+                .map(|ack| Action::Send(ack.clone().into()))
+                .collect()),
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn on_non_invite_response(&mut self, response: Response) -> Vec<Action> {
+        match (self.state, response.status_code.kind()) {
+            (State::Trying | State::Proceeding, StatusCodeKind::Provisional) => {
+                self.state = State::Proceeding;
+                vec![Action::Deliver(response.into())]
+            }
+            (State::Trying | State::Proceeding, _) => {
+                let mut actions = self.stop_timers();
+                self.state = State::Completed;
+                actions.push(Action::Deliver(response.into()));
+                actions.extend(wait(
+                    self.reliable,
+                    &mut self.state,
+                    Timer::K,
+                    self.timers.t4,
+                ));
+                actions
+            }
+            _ => vec![],
+        }
+    }
+
+    fn retransmit(&self, timer: Timer) -> Vec<Action> {
+        vec![
+            Action::Send(self.request.clone().into()),
+            Action::StartTimer(timer, self.interval),
+        ]
+    }
+
+    // Stops the timers of the Calling state for INVITE, and of the Trying/Proceeding states for
+    // non-INVITE transactions, since Timer E keeps running in the Proceeding state.
+    fn stop_timers(&self) -> Vec<Action> {
+        let (retransmit, timeout) = self.timers_of_state();
+
+        match (self.state, self.reliable) {
+            (State::Proceeding, _) if self.is_invite() => vec![],
+            (_, true) => vec![Action::CancelTimer(timeout)],
+            (_, false) => vec![
+                Action::CancelTimer(retransmit),
+                Action::CancelTimer(timeout),
+            ],
+        }
+    }
+
+    fn timers_of_state(&self) -> (Timer, Timer) {
+        match self.is_invite() {
+            true => (Timer::A, Timer::B),
+            false => (Timer::E, Timer::F),
+        }
+    }
+}
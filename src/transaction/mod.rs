@@ -0,0 +1,147 @@
+//! Sans-IO client and server transactions, as defined in
+//! [RFC3261 section 17](https://datatracker.ietf.org/doc/html/rfc3261#section-17), including the
+//! `Accepted` state of INVITE transactions that
+//! [RFC6026](https://datatracker.ietf.org/doc/html/rfc6026) introduced.
+//!
+//! A transaction does not do any IO by itself. It is fed with incoming messages, expired timers
+//! and transport errors and returns the [Action]s that the caller needs to take, like sending a
+//! message, passing a message to the transaction user (TU) or starting a timer. Whether the
+//! transport is reliable or not (thus if retransmissions are needed) is decided by the transport of
+//! the top `Via` of the request.
+//!
+//! Available only under the `transaction` feature.
+
+#[doc(hidden)]
+pub mod client;
+#[doc(hidden)]
+pub mod server;
+
+pub use client::ClientTransaction;
+pub use server::ServerTransaction;
+
+use crate::{
+    headers::untyped::ToTypedHeader, message::HeadersExt, Error, Request, SipMessage, Transport,
+};
+use std::time::Duration;
+
+/// The transaction timers of
+/// [RFC3261 section 17](https://datatracker.ietf.org/doc/html/rfc3261#section-17) and
+/// [RFC6026](https://datatracker.ietf.org/doc/html/rfc6026#section-8.4). Timer C is missing since
+/// it is a proxy timer, not a transaction one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Timer {
+    /// INVITE request retransmit interval, for UDP only.
+    A,
+    /// INVITE transaction timeout.
+    B,
+    /// Wait time for response retransmits.
+    D,
+    /// Non-INVITE request retransmit interval, for UDP only.
+    E,
+    /// Non-INVITE transaction timeout.
+    F,
+    /// INVITE response retransmit interval.
+    G,
+    /// Wait time for ACK receipt.
+    H,
+    /// Wait time for ACK retransmits.
+    I,
+    /// Wait time for non-INVITE request retransmits.
+    J,
+    /// Wait time for response retransmits.
+    K,
+    /// Wait time for accepted INVITE request retransmits.
+    L,
+    /// Wait time for retransmission of 2xx to INVITE or additional 2xx from other branches.
+    M,
+}
+
+/// The values that all transaction timers derive from, defaults to the values of
+/// [RFC3261 section 17.1.1.1](https://datatracker.ietf.org/doc/html/rfc3261#section-17.1.1.1).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerConfig {
+    /// RTT estimate.
+    pub t1: Duration,
+    /// The maximum retransmit interval for non-INVITE requests and INVITE responses.
+    pub t2: Duration,
+    /// Maximum duration a message will remain in the network.
+    pub t4: Duration,
+    /// Wait time for response retransmits of an unreliable INVITE client transaction, which has
+    /// to be at least 32 seconds.
+    pub timer_d: Duration,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self {
+            t1: Duration::from_millis(500),
+            t2: Duration::from_secs(4),
+            t4: Duration::from_secs(5),
+            timer_d: Duration::from_secs(32),
+        }
+    }
+}
+
+/// The state of a transaction. Client transactions start from `Calling` (INVITE) or `Trying`
+/// (non-INVITE), server transactions start from `Proceeding` (INVITE) or `Trying` (non-INVITE).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum State {
+    Calling,
+    Trying,
+    Proceeding,
+    Completed,
+    Confirmed,
+    Accepted,
+    Terminated,
+}
+
+/// What the caller of a transaction needs to do after feeding it with an event.
+///
+/// Once `Terminated` is returned the transaction can be dropped, along with any of its timers
+/// that are still running.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Send the message over the transport.
+    Send(SipMessage),
+    /// Pass the message to the TU.
+    Deliver(SipMessage),
+    /// Start (or restart) the timer, which should be fed back to the transaction when it fires.
+    StartTimer(Timer, Duration),
+    /// Stop the timer, if running.
+    CancelTimer(Timer),
+    /// Inform the TU that the transaction timed out.
+    Timeout,
+    /// Inform the TU that the transport failed.
+    TransportError,
+    /// The transaction reached the `Terminated` state.
+    Terminated,
+}
+
+fn is_reliable(request: &Request) -> Result<bool, Error> {
+    Ok(request.via_header()?.typed()?.transport != Transport::Udp)
+}
+
+// Starts the given timer on unreliable transports, or terminates the transaction right away on
+// reliable transports, where the timer would fire immediately.
+fn wait(reliable: bool, state: &mut State, timer: Timer, duration: Duration) -> Vec<Action> {
+    match reliable {
+        true => terminate(state),
+        false => vec![Action::StartTimer(timer, duration)],
+    }
+}
+
+fn terminate(state: &mut State) -> Vec<Action> {
+    *state = State::Terminated;
+    vec![Action::Terminated]
+}
+
+fn fail(state: &mut State, reason: Action) -> Vec<Action> {
+    match state {
+        State::Terminated => vec![],
+        _ => std::iter::once(reason).chain(terminate(state)).collect(),
+    }
+}
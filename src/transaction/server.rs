@@ -0,0 +1,198 @@
+use super::{fail, is_reliable, terminate, wait, Action, State, Timer, TimerConfig};
+use crate::{
+    message::TransactionKey, Error, Method, Request, Response, StatusCode, StatusCodeKind,
+};
+use std::time::Duration;
+
+/// The INVITE
+/// ([section 17.2.1](https://datatracker.ietf.org/doc/html/rfc3261#section-17.2.1)) and
+/// non-INVITE ([section 17.2.2](https://datatracker.ietf.org/doc/html/rfc3261#section-17.2.2))
+/// server transaction, depending on the method of the request.
+///
+/// [start](ServerTransaction::start) passes the request to the TU (sending a `100 Trying` for an
+/// INVITE), then any request matching the transaction [key](ServerTransaction::key), like
+/// retransmissions or the `ACK` of a non-2xx response (which, for an RFC2543 key, matches the
+/// key [with the To tag of the response](TransactionKey::with_response_to_tag)), should be fed
+/// through [on_request](ServerTransaction::on_request), while the responses of the TU go through
+/// [send_response](ServerTransaction::send_response). After a 2xx response to an INVITE the
+/// transaction moves to the `Accepted` state, where the TU is responsible for retransmitting the
+/// 2xx response and any `ACK` is passed to it.
+#[derive(Debug, Clone)]
+pub struct ServerTransaction {
+    key: TransactionKey,
+    request: Request,
+    state: State,
+    reliable: bool,
+    timers: TimerConfig,
+    interval: Duration,
+    last_response: Option<Response>,
+}
+
+impl ServerTransaction {
+    pub fn new(request: Request) -> Result<Self, Error> {
+        let timers = TimerConfig::default();
+
+        Ok(Self {
+            key: TransactionKey::server(&request)?,
+            reliable: is_reliable(&request)?,
+            state: match request.method {
+                Method::Invite => State::Proceeding,
+                Method::Ack => {
+                    return Err(Error::Unexpected(
+                        "ACK does not create a server transaction".into(),
+                    ))
+                }
+                _ => State::Trying,
+            },
+            request,
+            timers,
+            interval: timers.t1,
+            last_response: None,
+        })
+    }
+
+    pub fn with_timers(mut self, timers: TimerConfig) -> Self {
+        self.timers = timers;
+        self.interval = timers.t1;
+        self
+    }
+
+    pub fn key(&self) -> &TransactionKey {
+        &self.key
+    }
+
+    pub fn request(&self) -> &Request {
+        &self.request
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_invite(&self) -> bool {
+        self.request.method == Method::Invite
+    }
+
+    /// Passes the request to the TU, along with sending a `100 Trying` response for an INVITE.
+    pub fn start(&mut self) -> Vec<Action> {
+        let mut actions = vec![Action::Deliver(self.request.clone().into())];
+
+        if self.is_invite() {
+            let trying = self.request.response(StatusCode::Trying);
+            actions.push(Action::Send(trying.clone().into()));
+            self.last_response = Some(trying);
+        }
+
+        actions
+    }
+
+    pub fn on_request(&mut self, request: Request) -> Vec<Action> {
+        match (request.method, self.state) {
+            (Method::Ack, State::Completed) => {
+                let mut actions = vec![Action::CancelTimer(Timer::H)];
+                if !self.reliable {
+                    actions.push(Action::CancelTimer(Timer::G));
+                }
+                self.state = State::Confirmed;
+                actions.extend(wait(
+                    self.reliable,
+                    &mut self.state,
+                    Timer::I,
+                    self.timers.t4,
+                ));
+                actions
+            }
+            (Method::Ack, State::Accepted) => vec![Action::Deliver(request.into())],
+            (Method::Ack, _) => vec![],
+            (_, State::Proceeding | State::Completed) => self.resend_last_response(),
+            _ => vec![],
+        }
+    }
+
+    pub fn send_response(&mut self, response: Response) -> Vec<Action> {
+        match self.is_invite() {
+            true => self.send_invite_response(response),
+            false => self.send_non_invite_response(response),
+        }
+    }
+
+    pub fn on_timer(&mut self, timer: Timer) -> Vec<Action> {
+        match (timer, self.state) {
+            (Timer::G, State::Completed) => {
+                self.interval = std::cmp::min(self.interval * 2, self.timers.t2);
+                let mut actions = self.resend_last_response();
+                actions.push(Action::StartTimer(Timer::G, self.interval));
+                actions
+            }
+            (Timer::H, State::Completed) => fail(&mut self.state, Action::Timeout),
+            (Timer::I, State::Confirmed)
+            | (Timer::J, State::Completed)
+            | (Timer::L, State::Accepted) => terminate(&mut self.state),
+            _ => vec![],
+        }
+    }
+
+    pub fn on_transport_error(&mut self) -> Vec<Action> {
+        fail(&mut self.state, Action::TransportError)
+    }
+
+    fn send_invite_response(&mut self, response: Response) -> Vec<Action> {
+        match (self.state, response.status_code.kind()) {
+            (State::Proceeding, StatusCodeKind::Provisional) => self.send(response),
+            (State::Proceeding, StatusCodeKind::Successful) => {
+                let mut actions = self.send(response);
+                self.state = State::Accepted;
+                actions.push(Action::StartTimer(Timer::L, self.timers.t1 * 64));
+                actions
+            }
+            (State::Accepted, StatusCodeKind::Successful) => {
+                vec![Action::Send(response.into())]
+            }
+            (State::Proceeding, _) => {
+                let mut actions = self.send(response);
+                self.state = State::Completed;
+                actions.push(Action::StartTimer(Timer::H, self.timers.t1 * 64));
+                if !self.reliable {
+                    self.interval = self.timers.t1;
+                    actions.push(Action::StartTimer(Timer::G, self.interval));
+                }
+                actions
+            }
+            _ => vec![],
+        }
+    }
+
+    fn send_non_invite_response(&mut self, response: Response) -> Vec<Action> {
+        match (self.state, response.status_code.kind()) {
+            (State::Trying | State::Proceeding, StatusCodeKind::Provisional) => {
+                self.state = State::Proceeding;
+                self.send(response)
+            }
+            (State::Trying | State::Proceeding, _) => {
+                let mut actions = self.send(response);
+                self.state = State::Completed;
+                actions.extend(wait(
+                    self.reliable,
+                    &mut self.state,
+                    Timer::J,
+                    self.timers.t1 * 64,
+                ));
+                actions
+            }
+            _ => vec![],
+        }
+    }
+
+    fn send(&mut self, response: Response) -> Vec<Action> {
+        let actions = vec![Action::Send(response.clone().into())];
+        self.last_response = Some(response);
+        actions
+    }
+
+    fn resend_last_response(&self) -> Vec<Action> {
+        self.last_response
+            .iter()
+            .map(|response| Action::Send(response.clone().into()))
+            .collect()
+    }
+}
@@ -27,6 +27,18 @@ mod display {
             String::from("user:password")
         );
     }
+
+    #[test]
+    fn display3() {
+        assert_eq!(
+            Auth {
+                user: "alice@corp".into(),
+                password: Some("p@ss:word".into())
+            }
+            .to_string(),
+            String::from("alice%40corp:p%40ss%3Aword")
+        );
+    }
 }
 
 mod parser {
@@ -42,6 +54,17 @@ mod parser {
             }),
         );
     }
+
+    #[test]
+    fn parser_escaped() {
+        assert_eq!(
+            Tokenizer::from(("alice%40corp", Some("p%40ss%3Aword"))).try_into(),
+            Ok(Auth {
+                user: "alice@corp".into(),
+                password: Some("p@ss:word".into())
+            }),
+        );
+    }
 }
 
 mod tokenizer {
@@ -66,6 +89,17 @@ mod tokenizer {
         );
     }
 
+    #[test]
+    fn tokenizer2_u8() {
+        assert_eq!(
+            Tokenizer::tokenize("alice.smith%40corp;x=1@server2.com something".as_bytes()),
+            Ok((
+                "server2.com something".as_bytes(),
+                ("alice.smith%40corp;x=1".as_bytes(), None).into()
+            )),
+        );
+    }
+
     #[test]
     fn errors1() {
         assert_eq!(
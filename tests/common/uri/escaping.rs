@@ -0,0 +1,25 @@
+use rsip::common::uri::escaping::{escape, is_header_char, is_param_char, is_user_char, unescape};
+
+#[test]
+fn escape1() {
+    assert_eq!(escape("alice@corp", is_user_char), "alice%40corp");
+    assert_eq!(
+        escape("+1;phone-context=x", is_user_char),
+        "+1;phone-context=x"
+    );
+    assert_eq!(escape("a;b", is_param_char), "a%3Bb");
+    assert_eq!(escape("a&b", is_header_char), "a%26b");
+    assert_eq!(escape("€", is_header_char), "%E2%82%AC");
+}
+
+#[test]
+fn unescape1() {
+    assert_eq!(unescape(b"alice%40corp"), Ok("alice@corp".into()));
+    assert_eq!(unescape(b"%e2%82%ac"), Ok("€".into()));
+    assert!(unescape(b"50%").is_err());
+    assert!(unescape(b"%4").is_err());
+    assert!(unescape(b"%zz").is_err());
+    assert!(unescape(b"%FF").is_err());
+    assert!(unescape(b"%+1").is_err());
+    assert!(unescape(b"%-1").is_err());
+}
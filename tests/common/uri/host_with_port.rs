@@ -1,5 +1,6 @@
 use rsip::common::uri::{host_with_port::Tokenizer, Host, HostWithPort};
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use std::net::{IpAddr, Ipv6Addr};
 
 mod display {
     use super::*;
@@ -27,6 +28,22 @@ mod display {
             String::from("server2.com:5060")
         );
     }
+
+    #[test]
+    fn display3() {
+        assert_eq!(
+            HostWithPort {
+                host: Host::IpAddr(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))),
+                port: Some(5060.into())
+            }
+            .to_string(),
+            String::from("[2001:db8::1]:5060")
+        );
+        assert_eq!(
+            HostWithPort::from(Host::from("::1")).to_string(),
+            String::from("[::1]")
+        );
+    }
 }
 
 mod parser {
@@ -53,6 +70,47 @@ mod parser {
             })
         );
     }
+
+    #[test]
+    fn parser3() {
+        assert_eq!(
+            Tokenizer::from(("[2001:db8::1]".as_bytes(), Some("5060".as_bytes()))).try_into(),
+            Ok(HostWithPort {
+                host: Host::IpAddr("2001:db8::1".parse().unwrap()),
+                port: Some(5060.into())
+            })
+        );
+    }
+
+    #[test]
+    fn parser4() {
+        //zone ids can't be represented by IpAddr, so they are kept as is
+        assert_eq!(
+            Tokenizer::from(("[fe80::1%25eth0]", None)).try_into(),
+            Ok(HostWithPort {
+                host: Host::Domain("[fe80::1%25eth0]".into()),
+                port: None
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            HostWithPort::try_from("[2001:db8::1]:5070"),
+            Ok(("2001:db8::1".parse::<IpAddr>().unwrap(), 5070).into())
+        );
+        assert_eq!(
+            HostWithPort::try_from("[2001:db8::1]"),
+            Ok(Host::IpAddr("2001:db8::1".parse().unwrap()).into())
+        );
+        assert_eq!(
+            HostWithPort::try_from("[fe80::1%25eth0]:5060")
+                .map(|h| h.to_string())
+                .as_deref(),
+            Ok("[fe80::1%25eth0]:5060")
+        );
+    }
 }
 
 mod tokenizer {
@@ -88,6 +146,33 @@ mod tokenizer {
         );
     }
 
+    #[test]
+    fn tokenizer3_u8() {
+        assert_eq!(
+            Tokenizer::tokenize("[2001:db8::10]:5070;transport=tcp".as_bytes()),
+            Ok((
+                ";transport=tcp".as_bytes(),
+                ("[2001:db8::10]".as_bytes(), Some("5070".as_bytes())).into()
+            )),
+        );
+    }
+
+    #[test]
+    fn tokenizer3_str() {
+        assert_eq!(
+            Tokenizer::tokenize("[2001:db8::10:5070] something"),
+            Ok((" something", ("[2001:db8::10:5070]", None).into())),
+        );
+    }
+
+    #[test]
+    fn tokenizer4_str() {
+        assert_eq!(
+            Tokenizer::tokenize("[fe80::1%25eth0]:5060?Subject=hi"),
+            Ok(("?Subject=hi", ("[fe80::1%25eth0]", Some("5060")).into())),
+        );
+    }
+
     #[test]
     fn errors1() {
         assert_eq!(
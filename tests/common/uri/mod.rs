@@ -1,11 +1,14 @@
 pub mod auth;
+pub mod escaping;
 pub mod host_with_port;
 pub mod params;
 pub mod scheme;
+pub mod tel_uri;
+pub mod uri_header;
 pub mod uri_with_params;
 pub mod uri_with_params_list;
 
-use rsip::common::uri::{param::Maddr, Param, Scheme, Tokenizer, Uri};
+use rsip::common::uri::{param::Maddr, Param, Scheme, Tokenizer, Uri, UriHeader};
 use std::convert::TryInto;
 
 mod display {
@@ -88,6 +91,24 @@ mod display {
             String::from("sips:client.biloxi.example.com:5061;maddr=255.255.255.0;foo=192.0.2.201")
         );
     }
+
+    #[test]
+    fn display6() {
+        assert_eq!(
+            Uri {
+                scheme: Some(Scheme::Sip),
+                auth: Some(("bob", Option::<String>::None).into()),
+                host_with_port: ("biloxi.example.com", Option::<u16>::None).into(),
+                params: vec![Param::Transport(rsip::Transport::Tcp)],
+                headers: vec![
+                    UriHeader::new("Replaces", "12345@atlanta.example.com;to-tag=aa;from-tag=bb"),
+                    UriHeader::new("Subject", "hi there"),
+                ]
+            }
+            .to_string(),
+            String::from("sip:bob@biloxi.example.com;transport=TCP?Replaces=12345%40atlanta.example.com%3Bto-tag%3Daa%3Bfrom-tag%3Dbb&Subject=hi%20there")
+        );
+    }
 }
 
 mod parser {
@@ -257,6 +278,74 @@ mod parser {
         );
     }
 
+    #[test]
+    fn parser8() {
+        assert_eq!(
+            Tokenizer {
+                scheme: Some("sip".as_bytes().into()),
+                auth: Some(("bob".as_bytes(), None).into()),
+                host_with_port: ("biloxi.example.com".as_bytes(), None).into(),
+                params: vec![],
+                headers: Some(vec![
+                    ("Subject".as_bytes(), "hi%20there".as_bytes()).into(),
+                    ("priority".as_bytes(), "urgent".as_bytes()).into()
+                ]),
+                ..Default::default()
+            }
+            .try_into(),
+            Ok(Uri {
+                scheme: Some(Scheme::Sip),
+                auth: Some(("bob", Option::<String>::None).into()),
+                host_with_port: ("biloxi.example.com", Option::<u16>::None).into(),
+                params: vec![],
+                headers: vec![
+                    UriHeader::new("Subject", "hi there"),
+                    UriHeader::new("priority", "urgent")
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn parser9() {
+        let uri: Uri = std::convert::TryFrom::try_from(
+            "sip:bob@biloxi.example.com?Replaces=12345%40atlanta.example.com%3Bto-tag%3Daa%3Bfrom-tag%3Dbb",
+        )
+        .unwrap();
+
+        assert_eq!(
+            uri.host_with_port,
+            ("biloxi.example.com", Option::<u16>::None).into()
+        );
+        assert_eq!(
+            uri.header("replaces").map(|h| h.value()),
+            Some("12345@atlanta.example.com;to-tag=aa;from-tag=bb")
+        );
+        assert_eq!(uri.header("Subject"), None);
+        assert_eq!(
+            uri.to_string(),
+            "sip:bob@biloxi.example.com?Replaces=12345%40atlanta.example.com%3Bto-tag%3Daa%3Bfrom-tag%3Dbb"
+        );
+    }
+
+    #[test]
+    fn parser_escaped() {
+        let raw = "sip:alice%40corp:p%40ss@example.com;foo=a%3Bb?Subject=x%26y";
+        let uri: Uri = std::convert::TryFrom::try_from(raw).unwrap();
+
+        assert_eq!(uri.user(), Some("alice@corp"));
+        assert_eq!(uri.auth.as_ref().unwrap().password, Some("p@ss".into()));
+        assert_eq!(
+            uri.params,
+            vec![Param::Other("foo".into(), Some("a;b".into()))]
+        );
+        assert_eq!(
+            uri.header("subject"),
+            Some(&UriHeader::new("Subject", "x&y"))
+        );
+        assert_eq!(uri.to_string(), raw);
+    }
+
     #[cfg(feature = "test-utils")]
     #[test]
     fn parser_cycle() {
@@ -515,4 +604,147 @@ mod tokenizer {
             )),
         );
     }
+
+    #[test]
+    fn tokenizer12_u8() {
+        assert_eq!(
+            Tokenizer::tokenize(
+                "sip:bob@biloxi.example.com;transport=tcp?Subject=hi%20there&Priority=urgent something".as_bytes()
+            ),
+            Ok((
+                " something".as_bytes(),
+                Tokenizer {
+                    scheme: Some("sip".as_bytes().into()),
+                    auth: Some(("bob".as_bytes(), None).into()),
+                    host_with_port: ("biloxi.example.com".as_bytes(), None).into(),
+                    params: vec![("transport".as_bytes(), Some("tcp".as_bytes())).into()],
+                    headers: Some(vec![
+                        ("Subject".as_bytes(), "hi%20there".as_bytes()).into(),
+                        ("Priority".as_bytes(), "urgent".as_bytes()).into()
+                    ]),
+                    ..Default::default()
+                }
+            )),
+        );
+    }
+
+    #[test]
+    fn tokenizer13_u8() {
+        assert_eq!(
+            Tokenizer::tokenize("sip:biloxi.example.com:5060?Subject=hi>".as_bytes()),
+            Ok((
+                ">".as_bytes(),
+                Tokenizer {
+                    scheme: Some("sip".as_bytes().into()),
+                    auth: None,
+                    host_with_port: ("biloxi.example.com".as_bytes(), Some("5060".as_bytes()))
+                        .into(),
+                    params: vec![],
+                    headers: Some(vec![("Subject".as_bytes(), "hi".as_bytes()).into()]),
+                    ..Default::default()
+                }
+            )),
+        );
+    }
+}
+
+mod rfc_eq {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn rfc_eq(uri: &str, other_uri: &str) -> bool {
+        let uri = Uri::try_from(uri).unwrap();
+        let other_uri = Uri::try_from(other_uri).unwrap();
+
+        assert_eq!(uri.rfc_eq(&other_uri), other_uri.rfc_eq(&uri));
+        uri.rfc_eq(&other_uri)
+    }
+
+    //examples taken from rfc3261 section 19.1.4
+    #[test]
+    fn equivalent() {
+        assert!(rfc_eq(
+            "sip:%61lice@atlanta.com;transport=TCP",
+            "sip:alice@AtLanTa.CoM;Transport=tcp"
+        ));
+        assert!(rfc_eq(
+            "sip:carol@chicago.com",
+            "sip:carol@chicago.com;newparam=5"
+        ));
+        assert!(rfc_eq(
+            "sip:carol@chicago.com",
+            "sip:carol@chicago.com;security=on"
+        ));
+        assert!(rfc_eq(
+            "sip:carol@chicago.com;newparam=5",
+            "sip:carol@chicago.com;security=on"
+        ));
+        assert!(rfc_eq(
+            "sip:biloxi.com;transport=tcp;method=REGISTER?to=sip:bob%40biloxi.com",
+            "sip:biloxi.com;method=REGISTER;transport=tcp?to=sip:bob%40biloxi.com"
+        ));
+        assert!(rfc_eq(
+            "sip:alice@atlanta.com?subject=project%20x&priority=urgent",
+            "sip:alice@atlanta.com?priority=urgent&subject=project%20x"
+        ));
+    }
+
+    //examples taken from rfc3261 section 19.1.4
+    #[test]
+    fn not_equivalent() {
+        assert!(!rfc_eq(
+            "SIP:ALICE@AtLanTa.CoM;Transport=udp",
+            "sip:alice@AtLanTa.CoM;Transport=UDP"
+        ));
+        assert!(!rfc_eq("sip:bob@biloxi.com", "sip:bob@biloxi.com:5060"));
+        assert!(!rfc_eq(
+            "sip:bob@biloxi.com",
+            "sip:bob@biloxi.com;transport=udp"
+        ));
+        assert!(!rfc_eq(
+            "sip:bob@biloxi.com",
+            "sip:bob@biloxi.com:6000;transport=tcp"
+        ));
+        assert!(!rfc_eq(
+            "sip:carol@chicago.com",
+            "sip:carol@chicago.com?Subject=next%20meeting"
+        ));
+        assert!(!rfc_eq(
+            "sip:bob@phone21.boxesbybob.com",
+            "sip:bob@192.0.2.4"
+        ));
+    }
+
+    #[test]
+    fn scheme_and_params() {
+        assert!(!rfc_eq("sip:alice@atlanta.com", "sips:alice@atlanta.com"));
+        assert!(!rfc_eq(
+            "sip:alice@atlanta.com;ttl=15",
+            "sip:alice@atlanta.com"
+        ));
+        assert!(!rfc_eq(
+            "sip:alice@atlanta.com;user=phone",
+            "sip:alice@atlanta.com"
+        ));
+        assert!(!rfc_eq(
+            "sip:alice@atlanta.com;method=INVITE",
+            "sip:alice@atlanta.com"
+        ));
+        assert!(!rfc_eq(
+            "sip:alice@atlanta.com;maddr=239.255.255.1",
+            "sip:alice@atlanta.com"
+        ));
+        assert!(!rfc_eq(
+            "sip:alice@atlanta.com;foo=bar",
+            "sip:alice@atlanta.com;foo=baz"
+        ));
+        assert!(rfc_eq(
+            "sip:alice:secret@atlanta.com;lr;foo=BAR",
+            "sip:alice:secret@ATLANTA.com;FOO=bar;lr"
+        ));
+        assert!(!rfc_eq(
+            "sip:alice:secret@atlanta.com",
+            "sip:alice:SECRET@atlanta.com"
+        ));
+    }
 }
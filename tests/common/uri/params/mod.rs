@@ -11,6 +11,18 @@ mod display {
             String::from(";maddr=255.255.255.0")
         );
     }
+
+    #[test]
+    fn display2() {
+        assert_eq!(
+            Param::Tag("abc%zz".into()).to_string(),
+            String::from(";tag=abc%zz")
+        );
+        assert_eq!(
+            Param::Other("+sip.instance".into(), Some("\"<urn:uuid:1>\"".into())).to_string(),
+            String::from(";+sip.instance=\"<urn:uuid:1>\"")
+        );
+    }
 }
 
 mod parser {
@@ -31,6 +43,22 @@ mod parser {
             Ok(Param::Other("maddr".into(), None))
         );
     }
+
+    #[test]
+    fn parser3() {
+        assert_eq!(
+            Tokenizer::from(("tag".as_bytes(), Some("abc%41".as_bytes()))).try_into(),
+            Ok(Param::Tag("abc%41".into()))
+        );
+    }
+
+    #[test]
+    fn parser4() {
+        assert_eq!(
+            Tokenizer::from(("tag", Some("50%"))).try_into(),
+            Ok(Param::Tag("50%".into()))
+        );
+    }
 }
 
 mod tokenizer {
@@ -75,4 +103,26 @@ mod tokenizer {
             ))),
         );
     }
+
+    #[test]
+    fn tokenizer3_u8() {
+        assert_eq!(
+            Tokenizer::tokenize(";received=[2001:db8::1];tag=a%41&c".as_bytes()),
+            Ok((
+                ";tag=a%41&c".as_bytes(),
+                ("received".as_bytes(), Some("[2001:db8::1]".as_bytes())).into()
+            )),
+        );
+    }
+
+    #[test]
+    fn tokenizer_escaped_u8() {
+        assert_eq!(
+            Tokenizer::tokenize_uri_param(";foo=a%3Bb:[::1]&c;something".as_bytes()),
+            Ok((
+                ";something".as_bytes(),
+                ("foo".as_bytes(), Some("a%3Bb:[::1]&c".as_bytes())).into()
+            )),
+        );
+    }
 }
@@ -0,0 +1,182 @@
+use rsip::{
+    common::uri::{Param, Scheme, TelNumber, TelUri, Uri},
+    headers::{typed, untyped::ToTypedHeader, UntypedHeader},
+    Request,
+};
+use std::convert::{TryFrom, TryInto};
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            TelUri::new(TelNumber::Global("1-201-555-0123".into())).to_string(),
+            String::from("tel:+1-201-555-0123")
+        );
+    }
+
+    #[test]
+    fn display2() {
+        assert_eq!(
+            TelUri {
+                number: TelNumber::Local("7042".into()),
+                phone_context: Some("example.com".into()),
+                ext: Some("1234".into()),
+                isub: None,
+                npdi: true,
+                rn: Some("+1-202-555-0000".into()),
+                params: vec![Param::Other("cic".into(), Some("+1-5678".into()))],
+            }
+            .to_string(),
+            String::from(
+                "tel:7042;ext=1234;phone-context=example.com;cic=+1-5678;npdi;rn=+1-202-555-0000"
+            )
+        );
+    }
+}
+
+mod parser {
+    use super::*;
+
+    #[test]
+    fn parser1() {
+        assert_eq!(
+            TelUri::try_from("tel:+1-201-555-0123;phone-context=example.com;ext=1234"),
+            Ok(TelUri {
+                number: TelNumber::Global("1-201-555-0123".into()),
+                phone_context: Some("example.com".into()),
+                ext: Some("1234".into()),
+                isub: None,
+                npdi: false,
+                rn: None,
+                params: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parser2() {
+        let tel_uri = TelUri::try_from(
+            "TEL:(201)555-0123;isub=1411;rn=+1-202-555-0000;npdi;foo=bar;phone-context=+1",
+        )
+        .unwrap();
+
+        assert_eq!(tel_uri.number, TelNumber::Local("(201)555-0123".into()));
+        assert_eq!(tel_uri.number.digits(), "2015550123");
+        assert!(!tel_uri.number.is_global());
+        assert_eq!(tel_uri.phone_context, Some("+1".into()));
+        assert_eq!(tel_uri.isub, Some("1411".into()));
+        assert_eq!(tel_uri.rn, Some("+1-202-555-0000".into()));
+        assert!(tel_uri.npdi);
+        assert_eq!(
+            tel_uri.params,
+            vec![Param::Other("foo".into(), Some("bar".into()))]
+        );
+        assert_eq!(
+            tel_uri.to_string(),
+            "tel:(201)555-0123;isub=1411;phone-context=+1;foo=bar;npdi;rn=+1-202-555-0000"
+        );
+    }
+
+    #[test]
+    fn parser3() {
+        assert_eq!(
+            TelUri::try_from("tel:7042"),
+            Err(rsip::Error::MissingParam("phone-context".into()))
+        );
+        assert!(TelUri::try_from("tel:+1-abc").is_err());
+        assert!(TelUri::try_from("tel:+--").is_err());
+        assert!(TelUri::try_from("sip:+1-201-555-0123@example.com").is_err());
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(
+            TelNumber::Global("1-(201).555-0123".into()).digits(),
+            "+12015550123"
+        );
+        assert_eq!(TelNumber::Local("*7a-b#".into()).digits(), "*7AB#");
+    }
+}
+
+mod conversions {
+    use super::*;
+
+    #[test]
+    fn uri() {
+        let tel_uri = TelUri::try_from("tel:+1-201-555-0123;ext=1234").unwrap();
+        let uri: Uri = tel_uri.clone().into();
+
+        assert_eq!(uri.scheme, Some(Scheme::Tel));
+        assert_eq!(uri.to_string(), "tel:+1-201-555-0123;ext=1234");
+        assert_eq!(TelUri::try_from(uri), Ok(tel_uri));
+    }
+
+    #[test]
+    fn sip_uri() {
+        let tel_uri = TelUri::try_from("tel:+1-201-555-0123;ext=1234").unwrap();
+        let sip_uri = tel_uri.clone().into_sip_uri(("gateway.example.com", 5060));
+
+        assert_eq!(
+            sip_uri.to_string(),
+            "sip:+1-201-555-0123;ext=1234@gateway.example.com:5060;user=phone"
+        );
+        assert_eq!(
+            TelUri::try_from(
+                Uri::try_from("sip:+1-201-555-0123;ext=1234@gateway.example.com:5060;user=phone")
+                    .unwrap()
+            ),
+            Ok(tel_uri)
+        );
+    }
+
+    #[test]
+    fn sip_uri_user_phone_case_insensitive() {
+        assert_eq!(
+            TelUri::try_from("sip:+1-201-555-0123@gw.example.com;user=Phone"),
+            Ok(TelUri::new(TelNumber::Global("1-201-555-0123".into())))
+        );
+    }
+
+    #[test]
+    fn escaped_params() {
+        let tel_uri = TelUri::try_from("tel:+1-201-555-0123;foo=a%20b").unwrap();
+
+        assert_eq!(
+            tel_uri.params,
+            vec![Param::Other("foo".into(), Some("a b".into()))]
+        );
+        assert_eq!(tel_uri.to_string(), "tel:+1-201-555-0123;foo=a%20b");
+        assert_eq!(
+            tel_uri.into_sip_uri(("gw.example.com", 5060)).to_string(),
+            "sip:+1-201-555-0123;foo=a%20b@gw.example.com:5060;user=phone"
+        );
+    }
+
+    #[test]
+    fn headers_and_request_line() {
+        let to: typed::To = rsip::headers::To::new("Bob <tel:+1-201-555-0123>;tag=1928301774")
+            .typed()
+            .unwrap();
+        assert_eq!(
+            TelUri::try_from(&to.uri),
+            Ok(TelUri::new(TelNumber::Global("1-201-555-0123".into())))
+        );
+
+        let request: Request = concat!(
+            "INVITE tel:+1-201-555-0123 SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "Content-Length: 0\r\n\r\n"
+        )
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            TelUri::try_from(&request.uri),
+            Ok(TelUri::new(TelNumber::Global("1-201-555-0123".into())))
+        );
+        assert!(request
+            .to_string()
+            .starts_with("INVITE tel:+1-201-555-0123 SIP/2.0\r\n"));
+    }
+}
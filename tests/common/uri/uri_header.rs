@@ -0,0 +1,103 @@
+use rsip::common::uri::uri_header::{Tokenizer, UriHeader};
+use std::convert::TryInto;
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn display1() {
+        assert_eq!(
+            UriHeader::new("Subject", "hi").to_string(),
+            String::from("Subject=hi")
+        );
+    }
+
+    #[test]
+    fn display2() {
+        assert_eq!(
+            UriHeader::new(
+                "Replaces",
+                "12345@192.168.118.3;to-tag=12345;from-tag=5FFE-3994"
+            )
+            .to_string(),
+            String::from("Replaces=12345%40192.168.118.3%3Bto-tag%3D12345%3Bfrom-tag%3D5FFE-3994")
+        );
+    }
+}
+
+mod parser {
+    use super::*;
+
+    #[test]
+    fn parser1() {
+        assert_eq!(
+            Tokenizer::from(("Subject".as_bytes(), "project%20x".as_bytes())).try_into(),
+            Ok(UriHeader::new("Subject", "project x"))
+        );
+    }
+
+    #[test]
+    fn parser2() {
+        assert_eq!(
+            Tokenizer::from((
+                "Replaces",
+                "12345%40192.168.118.3%3bto-tag%3D12345%3Bfrom-tag%3D5FFE-3994"
+            ))
+            .try_into(),
+            Ok(UriHeader::new(
+                "Replaces",
+                "12345@192.168.118.3;to-tag=12345;from-tag=5FFE-3994"
+            ))
+        );
+    }
+
+    #[test]
+    fn parser3() {
+        assert_eq!(
+            Tokenizer::from(("Subject".as_bytes(), "%E2%82%AC".as_bytes())).try_into(),
+            Ok(UriHeader::new("Subject", "€"))
+        );
+    }
+
+    #[test]
+    fn parser4() {
+        assert!(TryInto::<UriHeader>::try_into(Tokenizer::from((
+            "Subject".as_bytes(),
+            "50%".as_bytes()
+        )))
+        .is_err());
+        assert!(TryInto::<UriHeader>::try_into(Tokenizer::from((
+            "Subject".as_bytes(),
+            "%zz".as_bytes()
+        )))
+        .is_err());
+    }
+}
+
+mod tokenizer {
+    use super::*;
+
+    #[test]
+    fn tokenizer1_u8() {
+        assert_eq!(
+            Tokenizer::tokenize("Subject=project%20x&Priority=urgent".as_bytes()),
+            Ok((
+                "&Priority=urgent".as_bytes(),
+                ("Subject".as_bytes(), "project%20x".as_bytes()).into()
+            )),
+        );
+    }
+
+    #[test]
+    fn tokenizer1_str() {
+        assert_eq!(
+            Tokenizer::tokenize("body= something"),
+            Ok((" something", ("body", "").into())),
+        );
+    }
+
+    #[test]
+    fn tokenizer2_u8() {
+        assert!(Tokenizer::tokenize("=value".as_bytes()).is_err());
+    }
+}
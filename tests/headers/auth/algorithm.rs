@@ -0,0 +1,41 @@
+use rsip::headers::auth::Algorithm;
+use std::convert::TryFrom;
+
+#[test]
+fn display() {
+    assert_eq!(Algorithm::Md5.to_string(), "MD5");
+    assert_eq!(Algorithm::Md5Sess.to_string(), "MD5-sess");
+    assert_eq!(Algorithm::Sha256.to_string(), "SHA-256");
+    assert_eq!(Algorithm::Sha256Sess.to_string(), "SHA-256-sess");
+    assert_eq!(Algorithm::Sha512256.to_string(), "SHA-512-256");
+    assert_eq!(Algorithm::Sha512256Sess.to_string(), "SHA-512-256-sess");
+}
+
+#[test]
+fn try_from() {
+    assert_eq!(Algorithm::try_from("SHA-256"), Ok(Algorithm::Sha256));
+    assert_eq!(
+        Algorithm::try_from("sha-256-sess"),
+        Ok(Algorithm::Sha256Sess)
+    );
+    assert_eq!(Algorithm::try_from("SHA-512-256"), Ok(Algorithm::Sha512256));
+    assert_eq!(
+        Algorithm::try_from("SHA-512-256-sess"),
+        Ok(Algorithm::Sha512256Sess)
+    );
+    assert!(Algorithm::try_from("SHA-512").is_err());
+}
+
+#[test]
+fn try_from_old_spellings() {
+    assert_eq!(Algorithm::try_from("SHA256"), Ok(Algorithm::Sha256));
+    assert_eq!(
+        Algorithm::try_from("SHA256-sess"),
+        Ok(Algorithm::Sha256Sess)
+    );
+    assert_eq!(Algorithm::try_from("SHA512"), Ok(Algorithm::Sha512));
+    assert_eq!(
+        Algorithm::try_from("SHA512-sess"),
+        Ok(Algorithm::Sha512Sess)
+    );
+}
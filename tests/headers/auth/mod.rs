@@ -1 +1,2 @@
+pub mod algorithm;
 pub mod scheme;
@@ -102,4 +102,27 @@ mod try_from_tokenizer {
             })
         );
     }
+
+    #[test]
+    fn try_from_hex_nonce_count() {
+        assert_eq!(
+            Tokenizer {
+                params: vec![
+                    ("nextnonce", "ea9c8e88df84f1cec4341ae6cbe5a359"),
+                    ("qop", "auth-int"),
+                    ("cnonce", "0a4f113b"),
+                    ("nc", "00000100")
+                ],
+            }
+            .try_into(),
+            Ok(AuthenticationInfo {
+                nextnonce: "ea9c8e88df84f1cec4341ae6cbe5a359".into(),
+                rspauth: None,
+                qop: Some(AuthQop::AuthInt {
+                    cnonce: "0a4f113b".into(),
+                    nc: 256
+                })
+            })
+        );
+    }
 }
@@ -28,7 +28,8 @@ mod display {
                         nc: 1
                     }),
                     response: "8ca523f5e9506fed4657c9700eebdbec".into(),
-                    opaque: Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS".into())
+                    opaque: Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS".into()),
+                    userhash: None
                 }
             ),
             String::from(concat!(
@@ -48,6 +49,47 @@ mod display {
 
         Ok(())
     }
+
+    #[test]
+    fn display_username_star_and_userhash() -> Result<(), rsip::Error> {
+        let authorization = Authorization {
+            scheme: Scheme::Digest,
+            username: "J\u{e4}s\u{f8}n Doe".into(),
+            realm: "api@example.org".into(),
+            nonce: "5TsQWLVdgBdmrQ0XsxbDODV+57QdFR34I9HAbC/RVvkK".into(),
+            uri: "/doe.json".try_into()?,
+            response: "9228976e82eb3141b93fb1604608f7fa243fadc7c03684b9b3f7bfbc3933cc43".into(),
+            algorithm: Some(rsip::headers::auth::Algorithm::Sha512256),
+            opaque: None,
+            qop: None,
+            userhash: None,
+        };
+
+        assert_eq!(
+            authorization.to_string(),
+            concat!(
+                "Digest ",
+                "username*=UTF-8''J%C3%A4s%C3%B8n%20Doe, ",
+                "realm=\"api@example.org\", ",
+                "nonce=\"5TsQWLVdgBdmrQ0XsxbDODV+57QdFR34I9HAbC/RVvkK\", ",
+                "uri=\"/doe.json\", ",
+                "response=\"9228976e82eb3141b93fb1604608f7fa243fadc7c03684b9b3f7bfbc3933cc43\", ",
+                "algorithm=SHA-512-256"
+            )
+        );
+
+        let authorization = Authorization {
+            username: "793263caabb707a56211940d90411ea4a575adeccb7e360aeb624ed06ece9b0b".into(),
+            userhash: Some(true),
+            ..authorization
+        };
+        assert!(authorization.to_string().starts_with(
+            "Digest username=\"793263caabb707a56211940d90411ea4a575adeccb7e360aeb624ed06ece9b0b\""
+        ));
+        assert!(authorization.to_string().ends_with(", userhash=true"));
+
+        Ok(())
+    }
 }
 
 mod try_from_tokenizer {
@@ -79,7 +121,8 @@ mod try_from_tokenizer {
                 response: "dfe56131d1958046689d83306477ecc".into(),
                 opaque: Some("".into()),
                 algorithm: None,
-                qop: None
+                qop: None,
+                userhash: None
             })
         );
     }
@@ -116,8 +159,79 @@ mod try_from_tokenizer {
                 qop: Some(AuthQop::Auth {
                     cnonce: "0a4f113b".into(),
                     nc: 1
-                })
+                }),
+                userhash: None
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_large_nonce_count() {
+        let authorization: Result<Authorization, rsip::Error> = Tokenizer {
+            scheme: "Digest".into(),
+            params: vec![
+                ("username", "bob"),
+                ("realm", "atlanta.example.com"),
+                ("nonce", "ea9c8e88df84f1cec4341ae6cbe5a359"),
+                ("uri", "sips:ss2.biloxi.example.com"),
+                ("response", "dfe56131d1958046689d83306477ecc"),
+                ("qop", "auth"),
+                ("cnonce", "0a4f113b"),
+                ("nc", "00000100"),
+            ],
+        }
+        .try_into();
+
+        assert_eq!(
+            authorization.unwrap().qop,
+            Some(AuthQop::Auth {
+                cnonce: "0a4f113b".into(),
+                nc: 256
             })
         );
     }
+
+    #[test]
+    fn try_from_username_star() {
+        let authorization: Result<Authorization, rsip::Error> = Tokenizer {
+            scheme: "Digest".into(),
+            params: vec![
+                ("username*", "UTF-8''J%C3%A4s%C3%B8n%20Doe"),
+                ("realm", "api@example.org"),
+                ("uri", "/doe.json"),
+                ("nonce", "5TsQWLVdgBdmrQ0XsxbDODV+57QdFR34I9HAbC/RVvkK"),
+                (
+                    "response",
+                    "9228976e82eb3141b93fb1604608f7fa243fadc7c03684b9b3f7bfbc3933cc43",
+                ),
+                ("algorithm", "SHA-512-256"),
+                ("userhash", "false"),
+            ],
+        }
+        .try_into();
+        let authorization = authorization.unwrap();
+
+        assert_eq!(authorization.username, "J\u{e4}s\u{f8}n Doe");
+        assert_eq!(authorization.userhash, Some(false));
+        assert_eq!(
+            authorization.algorithm,
+            Some(rsip::headers::auth::Algorithm::Sha512256)
+        );
+
+        let authorization: Result<Authorization, rsip::Error> = Tokenizer {
+            scheme: "Digest".into(),
+            params: vec![
+                ("username*", "ISO-8859-1''J%E4s%F8n"),
+                ("realm", "api@example.org"),
+                ("uri", "/doe.json"),
+                ("nonce", "5TsQWLVdgBdmrQ0XsxbDODV+57QdFR34I9HAbC/RVvkK"),
+                (
+                    "response",
+                    "9228976e82eb3141b93fb1604608f7fa243fadc7c03684b9b3f7bfbc3933cc43",
+                ),
+            ],
+        }
+        .try_into();
+        assert!(authorization.is_err());
+    }
 }
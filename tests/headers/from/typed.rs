@@ -74,3 +74,22 @@ mod try_from_tokenizer {
         Ok(())
     }
 }
+
+mod round_trip {
+    use super::*;
+    use rsip::headers::{untyped::ToTypedHeader, UntypedHeader};
+
+    #[test]
+    fn escaped_tag() -> Result<(), rsip::Error> {
+        let header = rsip::headers::From::new("<sip:a@b>;tag=abc%41");
+        let typed = header.typed()?;
+
+        assert_eq!(typed.tag(), Some(&uri::param::Tag::new("abc%41")));
+        assert_eq!(
+            rsip::headers::From::from(typed).value(),
+            "<sip:a@b>;tag=abc%41"
+        );
+
+        Ok(())
+    }
+}
@@ -27,7 +27,10 @@ pub mod via;
 pub mod warning;
 pub mod www_authenticate;
 
-use rsip::headers::{header::Tokenizer, Accept, Header};
+use rsip::headers::{
+    header::Tokenizer, Accept, CallId, ContentLength, Header, ReferTo, Subject, Via,
+};
+use std::convert::{TryFrom, TryInto};
 
 mod display {
     use super::*;
@@ -47,6 +50,110 @@ mod display {
             String::from("X-Forward: 202.45.213.14")
         );
     }
+    #[test]
+    fn compact_display1() {
+        assert_eq!(
+            format!(
+                "{:#}",
+                Header::Via(Via::new("SIP/2.0/UDP pc33.atlanta.com"))
+            ),
+            String::from("v: SIP/2.0/UDP pc33.atlanta.com")
+        );
+        assert_eq!(
+            format!("{:#}", Header::CallId(CallId::new("a84b4c76e66710"))),
+            String::from("i: a84b4c76e66710")
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                Header::ReferTo(ReferTo::new("<sip:bob@biloxi.com>"))
+            ),
+            String::from("r: <sip:bob@biloxi.com>")
+        );
+    }
+
+    #[test]
+    fn compact_display2() {
+        assert_eq!(
+            format!("{:#}", Header::Accept(Accept::new("application/sdp"))),
+            String::from("Accept: application/sdp")
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                Header::Other("X-Forward".into(), "202.45.213.14".into())
+            ),
+            String::from("X-Forward: 202.45.213.14")
+        );
+    }
+}
+
+mod parser {
+    use super::*;
+
+    #[test]
+    fn compact1() {
+        let header: Result<Header, rsip::Error> = Tokenizer {
+            name: "v".as_bytes(),
+            value: "SIP/2.0/UDP pc33.atlanta.com".as_bytes(),
+        }
+        .try_into();
+
+        assert_eq!(
+            header,
+            Ok(Header::Via(Via::new("SIP/2.0/UDP pc33.atlanta.com")))
+        );
+    }
+
+    #[test]
+    fn compact2() {
+        let header: Result<Header, rsip::Error> = Tokenizer {
+            name: "L".as_bytes(),
+            value: "0".as_bytes(),
+        }
+        .try_into();
+
+        assert_eq!(header, Ok(Header::ContentLength(ContentLength::new("0"))));
+    }
+
+    #[test]
+    fn folded1() {
+        let header: Result<Header, rsip::Error> = Tokenizer {
+            name: "Accept".as_bytes(),
+            value: "REGISTER, \r\n  INVITE\r\n\tBYE".as_bytes(),
+        }
+        .try_into();
+
+        assert_eq!(
+            header,
+            Ok(Header::Accept(Accept::new("REGISTER, INVITE BYE")))
+        );
+    }
+
+    #[test]
+    fn folded2() {
+        let header: Result<Header, rsip::Error> = Tokenizer {
+            name: "Subject".as_bytes(),
+            value: "\r\n  lunch".as_bytes(),
+        }
+        .try_into();
+
+        assert_eq!(header, Ok(Header::Subject(Subject::new("lunch"))));
+    }
+
+    #[test]
+    fn compact3() {
+        let header: Result<Header, rsip::Error> = Tokenizer {
+            name: "r".as_bytes(),
+            value: "<sip:bob@biloxi.com>".as_bytes(),
+        }
+        .try_into();
+
+        assert_eq!(
+            header,
+            Ok(Header::ReferTo(ReferTo::new("<sip:bob@biloxi.com>")))
+        );
+    }
 }
 
 mod tokenizer {
@@ -55,9 +162,9 @@ mod tokenizer {
     #[test]
     fn tokenizer1() {
         assert_eq!(
-            Tokenizer::tokenize(b"Accept: REGISTER, INVITE\r\n something"),
+            Tokenizer::tokenize(b"Accept: REGISTER, INVITE\r\nsomething"),
             Ok((
-                " something".as_bytes(),
+                "something".as_bytes(),
                 Tokenizer {
                     name: "Accept".as_bytes(),
                     value: "REGISTER, INVITE".as_bytes()
@@ -65,4 +172,226 @@ mod tokenizer {
             )),
         );
     }
+
+    #[test]
+    fn tokenizer2() {
+        assert_eq!(
+            Tokenizer::tokenize(b"Accept: REGISTER,\r\n  INVITE\r\n\tBYE\r\nsomething"),
+            Ok((
+                "something".as_bytes(),
+                Tokenizer {
+                    name: "Accept".as_bytes(),
+                    value: "REGISTER,\r\n  INVITE\r\n\tBYE".as_bytes()
+                }
+            )),
+        );
+    }
+
+    #[test]
+    fn tokenizer3() {
+        assert_eq!(
+            Tokenizer::tokenize(b"Subject:\r\n  lunch"),
+            Ok((
+                "".as_bytes(),
+                Tokenizer {
+                    name: "Subject".as_bytes(),
+                    value: "\r\n  lunch".as_bytes()
+                }
+            )),
+        );
+    }
+}
+
+mod multimap {
+    use super::*;
+    use rsip::{
+        headers::{Entry, MaxForwards, To},
+        message::HeadersExt,
+        Headers,
+    };
+
+    fn headers() -> Headers {
+        vec![
+            Via::new("SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds").into(),
+            Header::Other("X-Forward".into(), "202.45.213.14".into()),
+            Via::new("SIP/2.0/UDP bigbox3.site3.atlanta.com;branch=z9hG4bK77ef4c2312983.1").into(),
+            CallId::new("a84b4c76e66710").into(),
+            Header::Other("x-forward".into(), "10.0.0.1".into()),
+            ContentLength::new("0").into(),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn get() {
+        let headers = headers();
+
+        assert_eq!(
+            headers.get("via"),
+            Some(&Via::new("SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds").into())
+        );
+        assert_eq!(headers.get("v"), headers.get("Via"));
+        assert_eq!(
+            headers.get("Call-ID"),
+            Some(&CallId::new("a84b4c76e66710").into())
+        );
+        assert_eq!(headers.get("I"), headers.get("Call-ID"));
+        assert_eq!(
+            headers.get("X-FORWARD"),
+            Some(&Header::Other("X-Forward".into(), "202.45.213.14".into()))
+        );
+        assert_eq!(headers.get("To"), None);
+        assert_eq!(headers.get("X-Other"), None);
+    }
+
+    #[test]
+    fn get_all() {
+        let headers = headers();
+
+        assert_eq!(headers.get_all("Via").len(), 2);
+        assert_eq!(
+            headers.get_all("x-forward"),
+            vec![
+                &Header::Other("X-Forward".into(), "202.45.213.14".into()),
+                &Header::Other("x-forward".into(), "10.0.0.1".into())
+            ]
+        );
+        assert!(headers.get_all("Route").is_empty());
+    }
+
+    #[test]
+    fn append_keeps_order() {
+        let mut headers = headers();
+        headers.append(Via::new("SIP/2.0/TCP client.biloxi.com").into());
+        headers.push(To::new("<sip:bob@biloxi.com>").into());
+
+        assert_eq!(headers.len(), 8);
+        assert_eq!(
+            headers.get_all("Via").last(),
+            Some(&&Via::new("SIP/2.0/TCP client.biloxi.com").into())
+        );
+        assert_eq!(
+            headers.iter().last(),
+            Some(&To::new("<sip:bob@biloxi.com>").into())
+        );
+        assert!(headers.to_string().starts_with(
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\nX-Forward: 202.45.213.14\r\n"
+        ));
+    }
+
+    #[test]
+    fn insert() {
+        let mut headers = headers();
+        let removed = headers.insert(Via::new("SIP/2.0/TCP client.biloxi.com").into());
+
+        assert_eq!(removed.len(), 2);
+        assert_eq!(
+            headers.iter().next(),
+            Some(&Via::new("SIP/2.0/TCP client.biloxi.com").into())
+        );
+        assert_eq!(headers.get_all("Via").len(), 1);
+        assert_eq!(headers.len(), 5);
+        assert_eq!(
+            headers.get("Call-ID"),
+            Some(&CallId::new("a84b4c76e66710").into())
+        );
+
+        assert!(headers.insert(MaxForwards::new("70").into()).is_empty());
+        assert_eq!(headers.iter().last(), Some(&MaxForwards::new("70").into()));
+    }
+
+    #[test]
+    fn remove() {
+        let mut headers = headers();
+
+        assert_eq!(headers.remove("X-Forward").len(), 2);
+        assert_eq!(headers.remove("X-Forward"), vec![]);
+        assert_eq!(headers.len(), 4);
+        assert_eq!(
+            headers.get("Content-Length"),
+            Some(&ContentLength::new("0").into())
+        );
+    }
+
+    #[test]
+    fn unique_push() {
+        let mut headers = headers();
+        headers.unique_push(Header::Other("X-Forward".into(), "192.168.0.1".into()));
+
+        assert_eq!(headers.len(), 5);
+        assert_eq!(
+            headers.iter().last(),
+            Some(&Header::Other("X-Forward".into(), "192.168.0.1".into()))
+        );
+    }
+
+    #[test]
+    fn entry() {
+        let mut headers = headers();
+
+        match headers.entry("Via") {
+            Entry::Occupied(entry) => {
+                assert_eq!(entry.iter().count(), 2);
+                assert_eq!(entry.remove().len(), 2);
+            }
+            Entry::Vacant(_) => panic!("Via is missing"),
+        }
+        assert!(headers.get("Via").is_none());
+
+        headers
+            .entry("Max-Forwards")
+            .or_insert(MaxForwards::new("70").into());
+        *headers
+            .entry("Max-Forwards")
+            .or_insert(MaxForwards::new("69").into()) = MaxForwards::new("68").into();
+        assert_eq!(
+            headers.get("Max-Forwards"),
+            Some(&MaxForwards::new("68").into())
+        );
+    }
+
+    #[test]
+    fn mutation_changing_kind() {
+        let mut headers = headers();
+        for header in headers.iter_mut() {
+            if let Header::CallId(_) = header {
+                *header = To::new("<sip:bob@biloxi.com>").into();
+            }
+        }
+
+        assert_eq!(headers.get("Call-ID"), None);
+        assert_eq!(
+            headers.get("To"),
+            Some(&To::new("<sip:bob@biloxi.com>").into())
+        );
+
+        headers.push(CallId::new("843817637684230@998sdasdh09").into());
+        assert_eq!(
+            headers.get("Call-ID"),
+            Some(&CallId::new("843817637684230@998sdasdh09").into())
+        );
+        assert_eq!(headers.get_all("To").len(), 1);
+    }
+
+    #[test]
+    fn retain_and_headers_ext() {
+        let mut request = rsip::Request {
+            headers: headers(),
+            ..rsip::Request::try_from("OPTIONS sip:bob@biloxi.com SIP/2.0\r\n\r\n").unwrap()
+        };
+        request
+            .headers
+            .retain(|header| !matches!(header, Header::Via(_)));
+        assert!(request.via_header().is_err());
+
+        request
+            .call_id_header_mut()
+            .unwrap()
+            .replace("843817637684230@998sdasdh09");
+        assert_eq!(
+            request.call_id_header().unwrap().value(),
+            "843817637684230@998sdasdh09"
+        );
+        assert_eq!(request.headers, request.headers.clone());
+    }
 }
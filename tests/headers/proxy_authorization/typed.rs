@@ -28,7 +28,8 @@ mod display {
                         nc: 1
                     }),
                     response: "8ca523f5e9506fed4657c9700eebdbec".into(),
-                    opaque: Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS".into())
+                    opaque: Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS".into()),
+                    userhash: None
                 })
             ),
             String::from(concat!(
@@ -79,7 +80,8 @@ mod try_from_tokenizer {
                 response: "dfe56131d1958046689d83306477ecc".into(),
                 opaque: Some("".into()),
                 algorithm: None,
-                qop: None
+                qop: None,
+                userhash: None
             }))
         );
     }
@@ -116,7 +118,8 @@ mod try_from_tokenizer {
                 qop: Some(AuthQop::Auth {
                     cnonce: "0a4f113b".into(),
                     nc: 1
-                })
+                }),
+                userhash: None
             }))
         );
     }
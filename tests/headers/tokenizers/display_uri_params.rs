@@ -105,3 +105,28 @@ fn tokenizer_with_instance() {
         })
     );
 }
+
+#[test]
+fn tokenizer_with_uri_headers() {
+    assert_eq!(
+        DisplayUriParamsTokenizer::tokenize(
+            "<sip:bob@biloxi.example.com?Replaces=12345%40atlanta.example.com%3Bto-tag%3Daa>;x=y"
+        ),
+        Ok(DisplayUriParamsTokenizer {
+            display_name: None,
+            uri: uri::Tokenizer {
+                scheme: Some("sip".into()),
+                auth: Some(uri::auth::Tokenizer::from(("bob", None,))),
+                host_with_port: ("biloxi.example.com", None).into(),
+                params: vec![],
+                headers: Some(vec![(
+                    "Replaces",
+                    "12345%40atlanta.example.com%3Bto-tag%3Daa"
+                )
+                    .into()]),
+                ..Default::default()
+            },
+            params: vec![("x", Some("y")).into()],
+        })
+    );
+}
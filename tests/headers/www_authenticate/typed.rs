@@ -18,11 +18,12 @@ fn display() -> Result<(), rsip::Error> {
                 realm: "http-auth@example.org".into(),
                 nonce: "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v".into(),
                 algorithm: Some(auth::Algorithm::Sha256),
-                qop: Some(auth::Qop::Auth),
+                qop: vec![auth::Qop::Auth],
                 opaque: Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS".into()),
                 domain: None,
                 stale: None,
-                charset: None
+                charset: None,
+                userhash: None
             }
         ),
         String::from(concat!(
@@ -30,7 +31,7 @@ fn display() -> Result<(), rsip::Error> {
             "realm=\"http-auth@example.org\", ",
             "nonce=\"7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v\", ",
             "opaque=\"FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS\", ",
-            "algorithm=SHA256, ",
+            "algorithm=SHA-256, ",
             "qop=\"auth\"",
         ))
     );
@@ -59,8 +60,33 @@ fn from_tokenizer() {
             opaque: Some("5ccc069c403ebaf9f0171e9517f40e41".into()),
             stale: None,
             algorithm: None,
-            qop: Some("auth".try_into().expect("auth qop")),
-            charset: None
+            qop: vec!["auth".try_into().expect("auth qop")],
+            charset: None,
+            userhash: None
         })
     );
 }
+
+#[test]
+fn qop_list() {
+    let challenge: Result<WwwAuthenticate, rsip::Error> = Tokenizer {
+        scheme: "Digest".into(),
+        params: vec![
+            ("realm", "testrealm@host.com"),
+            ("qop", "auth, auth-int,auth-conf"),
+            ("nonce", "dcd98b7102dd2f0e8b11d0f600bfb0c093"),
+        ],
+    }
+    .try_into();
+    let challenge = challenge.unwrap();
+
+    assert_eq!(challenge.qop, vec![auth::Qop::Auth, auth::Qop::AuthInt]);
+    assert_eq!(
+        challenge.to_string(),
+        concat!(
+            "Digest realm=\"testrealm@host.com\", ",
+            "nonce=\"dcd98b7102dd2f0e8b11d0f600bfb0c093\", ",
+            "qop=\"auth,auth-int\""
+        )
+    );
+}
@@ -32,5 +32,9 @@ macro_rules! validate_typed_header_trait {
 pub mod common;
 pub mod headers;
 pub mod message;
+#[cfg(feature = "serde")]
+pub mod serialization;
 pub mod services;
 pub mod support;
+#[cfg(feature = "transaction")]
+pub mod transaction;
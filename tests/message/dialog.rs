@@ -0,0 +1,318 @@
+use rsip::{
+    headers::{typed, untyped::ToTypedHeader, CallId, UntypedHeader},
+    message::{Dialog, DialogId, DialogState, HeadersExt, Request, Response},
+    param::Tag,
+    Error, Method, Param, Uri,
+};
+use std::convert::TryFrom;
+
+fn invite(uri: &str) -> Request {
+    Request::try_from(format!(
+        concat!(
+            "INVITE {uri} SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP ss1.atlanta.example.com;branch=z9hG4bK2d4790.1\r\n",
+            "Via: SIP/2.0/UDP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+            "Record-Route: <sip:ss2.biloxi.example.com;lr>\r\n",
+            "Record-Route: <sip:ss1.atlanta.example.com;lr>\r\n",
+            "Max-Forwards: 69\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+            "CSeq: 1 INVITE\r\n",
+            "Contact: <sip:alice@client.atlanta.example.com;transport=tcp>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        uri = uri
+    ))
+    .unwrap()
+}
+
+fn response(status: &str, contact: &str) -> Response {
+    Response::try_from(format!(
+        concat!(
+            "SIP/2.0 {status}\r\n",
+            "Via: SIP/2.0/UDP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+            "Record-Route: <sip:ss2.biloxi.example.com;lr>, <sip:ss1.atlanta.example.com;lr>\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>;tag=314159\r\n",
+            "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+            "CSeq: 1 INVITE\r\n",
+            "Contact: <{contact}>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        status = status,
+        contact = contact
+    ))
+    .unwrap()
+}
+
+const CALL_ID: &str = "3848276298220188511@atlanta.example.com";
+const BOB_CONTACT: &str = "sip:bob@client.biloxi.example.com";
+
+fn uri(uri: &str) -> Uri {
+    Uri::try_from(uri).unwrap()
+}
+
+fn route_set(dialog: &Dialog) -> Vec<String> {
+    dialog.route_set.iter().map(|r| r.to_string()).collect()
+}
+
+#[test]
+fn dialog_id() {
+    let response = response("180 Ringing", BOB_CONTACT);
+
+    assert_eq!(
+        DialogId::uac(&response).unwrap(),
+        DialogId {
+            call_id: CallId::new(CALL_ID),
+            local_tag: Tag::new("9fxced76sl"),
+            remote_tag: Tag::new("314159"),
+        }
+    );
+    assert_eq!(
+        DialogId::uas(&response).unwrap(),
+        DialogId {
+            call_id: CallId::new(CALL_ID),
+            local_tag: Tag::new("314159"),
+            remote_tag: Tag::new("9fxced76sl"),
+        }
+    );
+    assert_eq!(
+        DialogId::uac(&invite("sip:bob@biloxi.example.com")),
+        Err(Error::MissingParam("tag".into()))
+    );
+}
+
+#[test]
+fn uac() {
+    let dialog = Dialog::uac(
+        &invite("sip:bob@biloxi.example.com"),
+        &response("200 OK", BOB_CONTACT),
+    )
+    .unwrap();
+
+    assert_eq!(
+        dialog,
+        Dialog {
+            id: DialogId {
+                call_id: CallId::new(CALL_ID),
+                local_tag: Tag::new("9fxced76sl"),
+                remote_tag: Tag::new("314159"),
+            },
+            state: DialogState::Confirmed,
+            local_seq: Some(1),
+            remote_seq: None,
+            local_uri: uri("sip:alice@atlanta.example.com"),
+            remote_uri: uri("sip:bob@biloxi.example.com"),
+            local_target: uri("sip:alice@client.atlanta.example.com;transport=tcp"),
+            remote_target: uri(BOB_CONTACT),
+            secure: false,
+            route_set: dialog.route_set.clone(),
+        }
+    );
+    assert_eq!(
+        route_set(&dialog),
+        vec![
+            "<sip:ss1.atlanta.example.com;lr>",
+            "<sip:ss2.biloxi.example.com;lr>"
+        ]
+    );
+}
+
+#[test]
+fn uac_early() {
+    let request = invite("sips:bob@biloxi.example.com");
+    let mut dialog = Dialog::uac(&request, &response("180 Ringing", BOB_CONTACT)).unwrap();
+
+    assert!(dialog.is_early());
+    assert!(dialog.secure);
+
+    dialog
+        .update(&response("200 OK", "sip:bob@192.0.2.4"))
+        .unwrap();
+    assert!(dialog.is_confirmed());
+    assert_eq!(dialog.remote_target, uri("sip:bob@192.0.2.4"));
+}
+
+#[test]
+fn uac_update_other_dialog() {
+    let mut dialog = Dialog::uac(
+        &invite("sip:bob@biloxi.example.com"),
+        &response("180 Ringing", BOB_CONTACT),
+    )
+    .unwrap();
+    let mut other = response("200 OK", BOB_CONTACT);
+    other
+        .to_header_mut()
+        .unwrap()
+        .mut_tag(Tag::new("a6c85cf"))
+        .unwrap();
+
+    assert!(dialog.update(&other).is_err());
+    assert!(dialog.is_early());
+}
+
+#[test]
+fn uac_not_dialog_creating_response() {
+    let request = invite("sip:bob@biloxi.example.com");
+
+    assert!(Dialog::uac(&request, &response("100 Trying", BOB_CONTACT)).is_err());
+    assert!(Dialog::uac(&request, &response("486 Busy Here", BOB_CONTACT)).is_err());
+}
+
+#[test]
+fn uas() {
+    let dialog = Dialog::uas(
+        &invite("sip:bob@biloxi.example.com"),
+        &response("200 OK", BOB_CONTACT),
+    )
+    .unwrap();
+
+    assert_eq!(
+        dialog,
+        Dialog {
+            id: DialogId {
+                call_id: CallId::new(CALL_ID),
+                local_tag: Tag::new("314159"),
+                remote_tag: Tag::new("9fxced76sl"),
+            },
+            state: DialogState::Confirmed,
+            local_seq: None,
+            remote_seq: Some(1),
+            local_uri: uri("sip:bob@biloxi.example.com"),
+            remote_uri: uri("sip:alice@atlanta.example.com"),
+            local_target: uri(BOB_CONTACT),
+            remote_target: uri("sip:alice@client.atlanta.example.com;transport=tcp"),
+            secure: false,
+            route_set: dialog.route_set.clone(),
+        }
+    );
+    assert_eq!(
+        route_set(&dialog),
+        vec![
+            "<sip:ss2.biloxi.example.com;lr>",
+            "<sip:ss1.atlanta.example.com;lr>"
+        ]
+    );
+}
+
+mod request {
+    use super::*;
+
+    fn dialog(record_route: &str) -> Dialog {
+        let mut response = response("200 OK", BOB_CONTACT);
+        response
+            .headers
+            .retain(|header| !matches!(header, rsip::Header::RecordRoute(_)));
+        if !record_route.is_empty() {
+            response
+                .headers
+                .push(rsip::headers::RecordRoute::new(record_route).into());
+        }
+
+        Dialog::uac(&invite("sip:bob@biloxi.example.com"), &response).unwrap()
+    }
+
+    fn via() -> typed::Via {
+        rsip::headers::Via::new(
+            "SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9;rport",
+        )
+        .typed()
+        .unwrap()
+    }
+
+    fn route(request: &Request) -> Option<String> {
+        request
+            .route_header()
+            .map(|route| route.value().to_string())
+    }
+
+    #[test]
+    fn bye() {
+        let mut dialog = dialog("");
+        let request = dialog.request(Method::Bye, via());
+
+        assert_eq!(request.method, Method::Bye);
+        assert_eq!(request.uri, uri(BOB_CONTACT));
+        assert_eq!(route(&request), None);
+        assert_eq!(request.cseq_header().unwrap().seq().unwrap(), 2);
+        assert_eq!(
+            request.cseq_header().unwrap().method().unwrap(),
+            Method::Bye
+        );
+        assert_eq!(dialog.local_seq, Some(2));
+        assert_eq!(request.max_forwards_header().unwrap().num().unwrap(), 70);
+        assert_eq!(
+            request.from_header().unwrap().value(),
+            "<sip:alice@atlanta.example.com>;tag=9fxced76sl"
+        );
+        assert_eq!(
+            request.to_header().unwrap().value(),
+            "<sip:bob@biloxi.example.com>;tag=314159"
+        );
+        assert_eq!(request.call_id_header().unwrap().value(), CALL_ID);
+        assert!(request.contact_header().is_err());
+    }
+
+    #[test]
+    fn fresh_branch() {
+        let mut dialog = dialog("");
+        let first = dialog.request(Method::Info, via());
+        let second = dialog.request(Method::Info, via());
+
+        let first_via = first.via_header().unwrap().typed().unwrap();
+        let second_via = second.via_header().unwrap().typed().unwrap();
+        assert_ne!(first_via.branch().unwrap().value(), "z9hG4bK74bf9");
+        assert_ne!(first_via.branch(), second_via.branch());
+        assert!(first_via
+            .params
+            .contains(&Param::Other("rport".into(), None)));
+        assert_eq!(
+            first_via
+                .params
+                .iter()
+                .filter(|param| matches!(param, Param::Branch(_)))
+                .count(),
+            1
+        );
+        assert_eq!(second.cseq_header().unwrap().seq().unwrap(), 3);
+    }
+
+    #[test]
+    fn re_invite() {
+        let mut dialog = dialog("");
+        dialog.local_seq = None;
+        let request = dialog.request(Method::Invite, via());
+
+        assert_eq!(request.cseq_header().unwrap().seq().unwrap(), 1);
+        assert_eq!(
+            request.contact_header().unwrap().uri().unwrap(),
+            uri("sip:alice@client.atlanta.example.com;transport=tcp")
+        );
+    }
+
+    #[test]
+    fn loose_routing() {
+        let mut dialog = dialog("<sip:p2.biloxi.example.com;lr>, <sip:p1.atlanta.example.com;lr>");
+        let request = dialog.request(Method::Bye, via());
+
+        assert_eq!(request.uri, uri(BOB_CONTACT));
+        assert_eq!(
+            route(&request),
+            Some("<sip:p1.atlanta.example.com;lr>,<sip:p2.biloxi.example.com;lr>".into())
+        );
+    }
+
+    #[test]
+    fn strict_routing() {
+        let mut dialog =
+            dialog("<sip:p2.biloxi.example.com;lr>, <sip:p1.atlanta.example.com;method=INVITE>");
+        let request = dialog.request(Method::Update, via());
+
+        assert_eq!(request.uri, uri("sip:p1.atlanta.example.com"));
+        assert_eq!(
+            route(&request),
+            Some(format!("<sip:p2.biloxi.example.com;lr>,<{}>", BOB_CONTACT))
+        );
+    }
+}
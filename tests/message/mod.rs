@@ -1,4 +1,11 @@
+pub mod dialog;
 pub mod has_headers;
 pub mod message;
+pub mod parse_options;
+pub mod positioned_error;
 pub mod request;
 pub mod response;
+pub mod sip_message_ref;
+pub mod stream_decoder;
+pub mod transaction_key;
+pub mod validation;
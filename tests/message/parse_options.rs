@@ -0,0 +1,125 @@
+use rsip::{
+    headers::UntypedHeader,
+    message::{HeadersExt, ParseOptions},
+    Component, ErrorKind, Header, Method, Request, Response, SipMessage,
+};
+use std::convert::TryFrom;
+
+const BROKEN: &str = concat!(
+    "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "Bad Header\r\n",
+    "To: Bob <sip:bob@biloxi.com>\r\n",
+    "Content-Length: 4\r\n\r\n",
+    "body"
+);
+
+#[test]
+fn strict() {
+    let error = Request::parse_with(BROKEN.as_bytes(), &ParseOptions::strict()).unwrap_err();
+    assert_eq!(error, Request::parse(BROKEN.as_bytes()).unwrap_err());
+}
+
+#[test]
+fn invalid_header_line() {
+    let (request, diagnostics) =
+        Request::parse_with(BROKEN.as_bytes(), &ParseOptions::lenient()).unwrap();
+
+    assert_eq!(request.method, Method::Invite);
+    assert_eq!(request.headers.iter().count(), 5);
+    assert_eq!(
+        request.headers.iter().nth(2),
+        Some(&Header::Invalid("Bad Header".into()))
+    );
+    assert_eq!(
+        request.to_header().unwrap().value(),
+        "Bob <sip:bob@biloxi.com>"
+    );
+    assert_eq!(request.body, b"body".to_vec());
+    assert!(request.to_string().contains("\r\nBad Header\r\n"));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, ErrorKind::Tokenize);
+    assert_eq!(diagnostics[0].component, Component::Headers);
+    assert_eq!(diagnostics[0].offset, BROKEN.find("Bad Header").unwrap());
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (4, 1));
+}
+
+#[test]
+fn bare_line_feeds() {
+    let input = concat!(
+        "SIP/2.0 200 OK\n",
+        "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\n",
+        "Subject: lunch\n",
+        "  at noon\r\n",
+        "Call-ID: a84b4c76e66710\n",
+        "\n",
+        "body"
+    );
+
+    assert!(Response::try_from(input).is_err());
+
+    let (response, diagnostics) =
+        Response::parse_with(input.as_bytes(), &ParseOptions::lenient()).unwrap();
+    assert!(diagnostics.is_empty());
+    assert_eq!(response.status_code, 200.into());
+    assert_eq!(response.headers.iter().count(), 3);
+    assert_eq!(
+        response.via_header().unwrap().value(),
+        "SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds"
+    );
+    assert_eq!(
+        response.headers.iter().nth(1),
+        Some(&rsip::headers::Subject::new("lunch at noon").into())
+    );
+    assert_eq!(response.call_id_header().unwrap().value(), "a84b4c76e66710");
+    assert_eq!(response.body, b"body".to_vec());
+}
+
+#[test]
+fn whitespace_around_colon() {
+    let input = concat!(
+        "OPTIONS sip:carol@chicago.com SIP/2.0\r\n",
+        "Via : SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bKhjhs8ass877\r\n",
+        "Max-Forwards\t:   70  \r\n",
+        "Call-ID:a84b4c76e66710\r\n\r\n"
+    );
+
+    assert!(Request::try_from(input).is_err());
+
+    let (message, diagnostics) =
+        SipMessage::parse_with(input.as_bytes(), &ParseOptions::lenient()).unwrap();
+    assert!(diagnostics.is_empty());
+    assert!(message.is_request());
+    assert_eq!(message.max_forwards_header().unwrap().num().unwrap(), 70);
+    assert_eq!(message.call_id_header().unwrap().value(), "a84b4c76e66710");
+    assert!(message.via_header().is_ok());
+}
+
+#[test]
+fn same_as_strict_when_valid() {
+    let input = concat!(
+        "SIP/2.0 180 Ringing\r\n",
+        "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+        "Subject: lunch\r\n",
+        " at noon\r\n",
+        "CSeq: 1 INVITE\r\n",
+        "Content-Length: 0\r\n\r\n"
+    );
+
+    assert_eq!(
+        SipMessage::parse_with(input.as_bytes(), &ParseOptions::lenient()),
+        Ok((SipMessage::try_from(input).unwrap(), vec![]))
+    );
+}
+
+#[test]
+fn invalid_start_line() {
+    let input = "INVITE sip:bob@biloxi.com SIP/2.0 x\nVia: SIP/2.0/UDP pc33.atlanta.com\n\n";
+    let error = Request::parse_with(input.as_bytes(), &ParseOptions::lenient()).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::TrailingInput);
+    assert_eq!(error.component, Component::StartLine);
+    assert_eq!((error.offset, error.line, error.column), (33, 1, 34));
+}
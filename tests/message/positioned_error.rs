@@ -0,0 +1,136 @@
+use rsip::{Component, Error, ErrorKind, PositionedError, Request, Response, SipMessage, UriPart};
+use std::convert::TryFrom;
+
+fn request(start_line: &str, headers: &str) -> String {
+    format!(
+        "{}\r\nVia: SIP/2.0/UDP pc33.atlanta.com\r\n{}\r\n",
+        start_line, headers
+    )
+}
+
+#[test]
+fn method() {
+    let input = request("REGISTE sip:bob@biloxi.com SIP/2.0", "");
+    let error = Request::parse(input.as_bytes()).unwrap_err();
+
+    assert_eq!(
+        error,
+        PositionedError {
+            kind: ErrorKind::Parse,
+            component: Component::Method,
+            offset: 0,
+            line: 1,
+            column: 1,
+            error: Request::try_from(input.as_str()).unwrap_err(),
+        }
+    );
+    assert!(
+        matches!(error.error, Error::ParseError(ref inner) if inner.contains("invalid method: REGISTE"))
+    );
+}
+
+#[test]
+fn uri_parts() {
+    let error = Request::parse(request("INVITE sip:bob@biloxi.com:abc SIP/2.0", "").as_bytes())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Parse);
+    assert_eq!(error.component, Component::Uri(UriPart::HostWithPort));
+    assert_eq!((error.offset, error.line, error.column), (15, 1, 16));
+
+    let error =
+        Request::parse(request("INVITE sip:bob@biloxi.com;transport= SIP/2.0", "").as_bytes())
+            .unwrap_err();
+    assert_eq!(
+        error.component,
+        Component::Uri(UriPart::Param("transport".into()))
+    );
+    assert_eq!((error.offset, error.line, error.column), (26, 1, 27));
+}
+
+#[test]
+fn uri_tokenizer() {
+    let input = request("INVITE sip: SIP/2.0", "");
+    let error = Request::parse(input.as_bytes()).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Tokenize);
+    assert_eq!(error.component, Component::Uri(UriPart::HostWithPort));
+    assert_eq!((error.offset, error.line, error.column), (7, 1, 8));
+    assert_eq!(error.error, Request::try_from(input.as_str()).unwrap_err());
+}
+
+#[test]
+fn version() {
+    let error =
+        Request::parse(request("INVITE sip:bob@biloxi.com SIP/3.x", "").as_bytes()).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Tokenize);
+    assert_eq!(error.component, Component::Version);
+    assert_eq!((error.offset, error.line, error.column), (26, 1, 27));
+}
+
+#[test]
+fn status_code() {
+    let input = "SIP/2.0 abc OK\r\nVia: SIP/2.0/UDP pc33.atlanta.com\r\n\r\n";
+    let error = Response::parse(input.as_bytes()).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Parse);
+    assert_eq!(error.component, Component::StatusCode);
+    assert_eq!((error.offset, error.line, error.column), (8, 1, 9));
+    assert_eq!(error.error, Response::try_from(input).unwrap_err());
+}
+
+#[test]
+fn trailing_header_input() {
+    let input = request(
+        "INVITE sip:bob@biloxi.com SIP/2.0",
+        "Max-Forwards: 70\r\nBad Header\r\nTo: Bob <sip:bob@biloxi.com>\r\n",
+    );
+    let error = SipMessage::parse(input.as_bytes()).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::TrailingInput);
+    assert_eq!(error.component, Component::Headers);
+    assert_eq!(error.offset, input.find("Bad Header").unwrap());
+    assert_eq!((error.line, error.column), (4, 1));
+    assert!(
+        matches!(error.error, Error::TokenizeError(ref inner) if inner.contains("failed to tokenize headers"))
+    );
+    assert_eq!(error.error, Request::try_from(input.as_str()).unwrap_err());
+}
+
+#[test]
+fn missing_headers_end() {
+    let input = "SIP/2.0 200 OK\r\nVia: SIP/2.0/UDP pc33.atlanta.com";
+    let error = SipMessage::parse(input.as_bytes()).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Tokenize);
+    assert_eq!(error.component, Component::Headers);
+    assert_eq!((error.offset, error.line, error.column), (16, 2, 1));
+}
+
+#[test]
+fn display() {
+    let input = request("INVITE sip:bob@biloxi.com;transport= SIP/2.0", "");
+    let error = Request::parse(input.as_bytes()).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "{} (at line 1, column 27, in uri param transport)",
+            Request::try_from(input.as_str()).unwrap_err()
+        )
+    );
+    assert_eq!(Error::from(error.clone()), error.error);
+}
+
+#[test]
+fn success() {
+    let input = request(
+        "INVITE sip:bob@biloxi.com SIP/2.0",
+        "Max-Forwards: 70\r\nContent-Length: 0\r\n",
+    );
+
+    assert_eq!(
+        SipMessage::parse(input.as_bytes()),
+        Ok(SipMessage::try_from(input.as_str()).unwrap())
+    );
+}
@@ -159,6 +159,576 @@ fn parser_loop() -> Result<(), rsip::Error> {
     Ok(())
 }
 
+#[test]
+fn compact_parser_loop() -> Result<(), rsip::Error> {
+    use rsip::message::HeadersExt;
+
+    let req: &str = concat!(
+        "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+        "v: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+        "Max-Forwards: 70\r\n",
+        "t: Bob <sip:bob@biloxi.com>\r\n",
+        "f: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+        "i: a84b4c76e66710@pc33.atlanta.com\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "m: <sip:alice@pc33.atlanta.com>\r\n",
+        "l: 0\r\n\r\n"
+    );
+    let parsed_request = Request::try_from(req)?;
+
+    assert_eq!(
+        parsed_request.via_header()?,
+        &Via::new("SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds")
+    );
+    assert_eq!(
+        parsed_request.call_id_header()?,
+        &CallId::new("a84b4c76e66710@pc33.atlanta.com")
+    );
+    assert_eq!(format!("{:#}", parsed_request), req);
+    assert_eq!(
+        parsed_request.to_string(),
+        concat!(
+            "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: Bob <sip:bob@biloxi.com>\r\n",
+            "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Contact: <sip:alice@pc33.atlanta.com>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn folded_parser() -> Result<(), rsip::Error> {
+    use rsip::message::HeadersExt;
+
+    //adapted from the wsinv torture test of rfc4475 (section 3.1.1.1), without the headers that
+    //have whitespace between the header name and the colon
+    let req: &str = concat!(
+        "INVITE sip:vivekg@chair-dnrc.example.com;unknownparam SIP/2.0\r\n",
+        "To: \"I have a user name of extremeextremeextremeextremeextremeextremeextremeextremeextremeextreme proportion\"\r\n",
+        " <sip:vivekg@chair-dnrc.example.com>\r\n",
+        "from: \"J Rosenberg \\\\\\\"\" <sip:jdrosen@example.com>\r\n",
+        "  ;\r\n",
+        "  tag = 98asjd8\r\n",
+        "MaX-fOrWaRdS: 0068\r\n",
+        "Call-ID: wsinv.ndaksdj@192.0.2.1\r\n",
+        "cseq: 0009\r\n",
+        "  INVITE\r\n",
+        "NewFangledHeader:   newfangled value\r\n",
+        " continued newfangled value\r\n",
+        "UnknownHeaderWithUnusualValue: ;;,,;;,;\r\n",
+        "Content-Type: application/sdp\r\n",
+        "Route:\r\n",
+        " <sip:services.example.com;lr;unknownwith=value;unknown-no-value>\r\n",
+        "v:  SIP  / 2.0  / TCP     spindle.example.com   ;\r\n",
+        "  branch  =   z9hG4bK9ikj8  ,\r\n",
+        " SIP  /    2.0   / UDP  192.168.255.111   ; branch=\r\n",
+        " z9hG4bK30239\r\n",
+        "m:\"Quoted string \\\"\\\"\" <sip:jdrosen@example.com> ; newparam =\r\n",
+        "      newvalue ;\r\n",
+        "  secondparam ; q = 0.33\r\n\r\n",
+    );
+
+    let request = Request::try_from(req)?;
+
+    assert_eq!(
+        request.to_header()?,
+        &To::new("\"I have a user name of extremeextremeextremeextremeextremeextremeextremeextremeextremeextreme proportion\" <sip:vivekg@chair-dnrc.example.com>")
+    );
+    assert_eq!(request.cseq_header()?, &CSeq::new("0009 INVITE"));
+    assert_eq!(request.cseq_header()?.seq()?, 9);
+    assert_eq!(
+        request.route_header(),
+        Some(&Route::new(
+            "<sip:services.example.com;lr;unknownwith=value;unknown-no-value>"
+        ))
+    );
+    assert!(request.headers.iter().any(|h| h
+        == &rsip::Header::Other(
+            "NewFangledHeader".into(),
+            "newfangled value continued newfangled value".into()
+        )));
+    assert_eq!(
+        request.via_header()?,
+        &Via::new("SIP  / 2.0  / TCP     spindle.example.com   ; branch  =   z9hG4bK9ikj8  , SIP  /    2.0   / UDP  192.168.255.111   ; branch= z9hG4bK30239")
+    );
+    assert_eq!(
+        request.contact_header()?,
+        &Contact::new("\"Quoted string \\\"\\\"\" <sip:jdrosen@example.com> ; newparam = newvalue ; secondparam ; q = 0.33")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn folded_parser_loop() -> Result<(), rsip::Error> {
+    use rsip::{message::HeadersExt, typed};
+
+    let req: &str = concat!(
+        "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+        "Via: SIP/2.0/UDP\r\n",
+        "\tpc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+        "Max-Forwards: 70\r\n",
+        "To: Bob <sip:bob@biloxi.com>\r\n",
+        "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+        "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "Authorization: Digest username=\"alice\",\r\n",
+        "  realm=\"atlanta.com\",\r\n",
+        "  nonce=\"84a4cc6f3082121f32b42a2187831a9e\", uri=\"sip:bob@biloxi.com\",\r\n",
+        "  response=\"7587245234b3434cc3412213e5f113a5432\"\r\n",
+        "Content-Length: 0\r\n\r\n"
+    );
+    let request = Request::try_from(req)?;
+
+    let via: typed::Via = request.via_header()?.typed()?;
+    assert_eq!(via.branch()?, &rsip::param::Branch::new("z9hG4bK776asdhds"));
+    let authorization: typed::Authorization = request
+        .authorization_header()
+        .expect("authorization header")
+        .typed()?;
+    assert_eq!(authorization.realm, "atlanta.com");
+    assert_eq!(
+        authorization.response,
+        "7587245234b3434cc3412213e5f113a5432"
+    );
+    assert_eq!(
+        request.to_string(),
+        concat!(
+            "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+            "Via: SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: Bob <sip:bob@biloxi.com>\r\n",
+            "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 INVITE\r\n",
+            "Authorization: Digest username=\"alice\", realm=\"atlanta.com\", nonce=\"84a4cc6f3082121f32b42a2187831a9e\", uri=\"sip:bob@biloxi.com\", response=\"7587245234b3434cc3412213e5f113a5432\"\r\n",
+            "Content-Length: 0\r\n\r\n"
+        )
+    );
+
+    Ok(())
+}
+
+//examples taken from the IPv6 torture tests of rfc5118
+mod ipv6 {
+    use super::*;
+    use rsip::{message::HeadersExt, typed};
+    use std::net::IpAddr;
+
+    #[test]
+    fn ipv6_good() -> Result<(), rsip::Error> {
+        let req: &str = concat!(
+            "REGISTER sip:[2001:db8::10] SIP/2.0\r\n",
+            "To: sip:user@example.com\r\n",
+            "From: sip:user@example.com;tag=81x2\r\n",
+            "Via: SIP/2.0/UDP [2001:db8::9:1];branch=z9hG4bKas3-111\r\n",
+            "Call-ID: SSG9559905523997077@hlau_4100\r\n",
+            "Max-Forwards: 70\r\n",
+            "Contact: \"Caller\" <sip:caller@[2001:db8::1]>\r\n",
+            "CSeq: 98176 REGISTER\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+        let request = Request::try_from(req)?;
+
+        assert_eq!(
+            request.uri.host(),
+            &uri::Host::IpAddr("2001:db8::10".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(request.uri.port(), None);
+
+        let via: typed::Via = request.via_header()?.typed()?;
+        assert_eq!(
+            via.sent_by().host(),
+            &uri::Host::IpAddr("2001:db8::9:1".parse::<IpAddr>().unwrap())
+        );
+
+        let contact: typed::Contact = request.contact_header()?.typed()?;
+        assert_eq!(
+            contact.uri.host(),
+            &uri::Host::IpAddr("2001:db8::1".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(request.to_string(), req);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ipv6_bad() {
+        let req: &str = concat!(
+            "REGISTER sip:2001:db8::10 SIP/2.0\r\n",
+            "To: sip:user@example.com\r\n",
+            "From: sip:user@example.com;tag=81x2\r\n",
+            "Via: SIP/2.0/UDP [2001:db8::9:1];branch=z9hG4bKas3-111\r\n",
+            "Call-ID: SSG9559905523997077@hlau_4100\r\n",
+            "Max-Forwards: 70\r\n",
+            "Contact: \"Caller\" <sip:caller@[2001:db8::1]>\r\n",
+            "CSeq: 98176 REGISTER\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+
+        assert!(Request::try_from(req).is_err());
+    }
+
+    #[test]
+    fn port_ambiguous() -> Result<(), rsip::Error> {
+        let req: &str = concat!(
+            "REGISTER sip:[2001:db8::10:5070] SIP/2.0\r\n",
+            "To: sip:user@example.com\r\n",
+            "From: sip:user@example.com;tag=81x2\r\n",
+            "Via: SIP/2.0/UDP [2001:db8::9:1];branch=z9hG4bKas3-111\r\n",
+            "Call-ID: SSG9559905523997077@hlau_4100\r\n",
+            "Contact: \"Caller\" <sip:caller@[2001:db8::1]>\r\n",
+            "Max-Forwards: 70\r\n",
+            "CSeq: 98176 REGISTER\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+        let request = Request::try_from(req)?;
+        assert_eq!(
+            request.uri.host(),
+            &uri::Host::IpAddr("2001:db8::10:5070".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(request.uri.port(), None);
+
+        let request =
+            Request::try_from(req.replacen("[2001:db8::10:5070]", "[2001:db8::10]:5070", 1))?;
+        assert_eq!(
+            request.uri.host(),
+            &uri::Host::IpAddr("2001:db8::10".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(request.uri.port(), Some(&5070.into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn via_received_param() -> Result<(), rsip::Error> {
+        let req: &str = concat!(
+            "BYE sip:[2001:db8::10] SIP/2.0\r\n",
+            "To: sip:user@example.com;tag=bd76ya\r\n",
+            "From: sip:user@example.com;tag=81x2\r\n",
+            "Via: SIP/2.0/UDP [2001:db8::9:1];received=[2001:db8::9:255];branch=z9hG4bKas3-111\r\n",
+            "Call-ID: SSG9559905523997077@hlau_4100\r\n",
+            "Max-Forwards: 70\r\n",
+            "CSeq: 321 BYE\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+
+        for req in [
+            req.to_string(),
+            req.replace("received=[2001:db8::9:255]", "received=2001:db8::9:255"),
+        ] {
+            let request = Request::try_from(req.as_str())?;
+            let via: typed::Via = request.via_header()?.typed()?;
+
+            assert_eq!(
+                via.received(),
+                Ok(Some("2001:db8::9:255".parse::<IpAddr>().unwrap()))
+            );
+            assert_eq!(request.to_string(), req);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn ipv4_mapped() -> Result<(), rsip::Error> {
+        let req: &str = concat!(
+            "INVITE sip:user@[2001:db8::10] SIP/2.0\r\n",
+            "To: sip:user@[2001:db8::10]\r\n",
+            "From: sip:user@example.com;tag=468bd76ya\r\n",
+            "Via: SIP/2.0/UDP [::ffff:192.0.2.10]:19823;branch=z9hG4bKbh19\r\n",
+            "Call-ID: SSG95523997077@hlau_4100\r\n",
+            "Contact: \"Caller\" <sip:caller@[::ffff:192.0.2.10]:19823>\r\n",
+            "Max-Forwards: 70\r\n",
+            "CSeq: 921 INVITE\r\n",
+            "Content-Length: 0\r\n\r\n"
+        );
+        let request = Request::try_from(req)?;
+
+        let via: typed::Via = request.via_header()?.typed()?;
+        assert_eq!(
+            via.sent_by().host_with_port,
+            ("::ffff:192.0.2.10".parse::<IpAddr>().unwrap(), 19823).into()
+        );
+        let contact: typed::Contact = request.contact_header()?.typed()?;
+        assert_eq!(
+            contact.uri.to_string(),
+            "sip:caller@[::ffff:192.0.2.10]:19823"
+        );
+
+        Ok(())
+    }
+}
+
+mod response {
+    use super::*;
+    use rsip::{message::HeadersExt, param::Tag, StatusCode};
+
+    fn request() -> Request {
+        Request::try_from(concat!(
+            "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n",
+            "Via: SIP/2.0/TCP ss1.atlanta.example.com:5060;branch=z9hG4bK2d4790.1;received=192.0.2.111\r\n",
+            "Via: SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9;received=192.0.2.101\r\n",
+            "Max-Forwards: 69\r\n",
+            "Record-Route: <sip:ss1.atlanta.example.com;lr>\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "Call-ID: 2xTb9vxSit55XU7p8@atlanta.example.com\r\n",
+            "CSeq: 1 INVITE\r\n",
+            "Timestamp: 54\r\n",
+            "Contact: <sip:alice@client.atlanta.example.com;transport=tcp>\r\n",
+            "Content-Type: application/sdp\r\n",
+            "Content-Length: 4\r\n\r\n",
+            "v=0\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn copies_mandatory_headers() {
+        let response = request()
+            .response(StatusCode::Ringing)
+            .with_to_tag(Tag::new("8321234356"))
+            .unwrap();
+
+        assert_eq!(response.status_code, StatusCode::Ringing);
+        assert_eq!(response.body, Vec::<u8>::new());
+        assert_eq!(
+            response.headers,
+            vec![
+                Via::new("SIP/2.0/TCP ss1.atlanta.example.com:5060;branch=z9hG4bK2d4790.1;received=192.0.2.111").into(),
+                Via::new("SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9;received=192.0.2.101").into(),
+                RecordRoute::new("<sip:ss1.atlanta.example.com;lr>").into(),
+                From::new("Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl").into(),
+                To::new("Bob <sip:bob@biloxi.example.com>;tag=8321234356").into(),
+                CallId::new("2xTb9vxSit55XU7p8@atlanta.example.com").into(),
+                CSeq::new("1 INVITE").into(),
+                ContentLength::new("0").into(),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn trying() {
+        let response = request()
+            .response(StatusCode::Trying)
+            .with_to_tag(Tag::new("8321234356"))
+            .unwrap();
+
+        assert_eq!(response.to_header().unwrap().tag().unwrap(), None);
+        assert_eq!(
+            response
+                .headers
+                .iter()
+                .find(|header| matches!(header, Header::Timestamp(_))),
+            Some(&Timestamp::new("54").into())
+        );
+    }
+
+    #[test]
+    fn keeps_existing_to_tag() {
+        let mut request = request();
+        request
+            .to_header_mut()
+            .unwrap()
+            .mut_tag(Tag::new("a6c85cf"))
+            .unwrap();
+
+        let response = request
+            .response(StatusCode::OK)
+            .with_to_tag(Tag::new("8321234356"))
+            .unwrap();
+
+        assert_eq!(
+            response.to_header().unwrap().tag().unwrap(),
+            Some(Tag::new("a6c85cf"))
+        );
+        assert!(!response
+            .headers
+            .iter()
+            .any(|header| matches!(header, Header::Timestamp(_))));
+    }
+
+    #[test]
+    fn record_route() {
+        let has_record_route = |request: &Request, status_code| {
+            request
+                .response(status_code)
+                .headers
+                .iter()
+                .any(|header| matches!(header, Header::RecordRoute(_)))
+        };
+
+        let mut request = request();
+        assert!(has_record_route(&request, StatusCode::OK));
+        assert!(!has_record_route(&request, StatusCode::Trying));
+        assert!(!has_record_route(&request, StatusCode::BusyHere));
+
+        request.method = rsip::Method::Subscribe;
+        assert!(has_record_route(&request, StatusCode::Accepted));
+
+        request.method = rsip::Method::Options;
+        assert!(!has_record_route(&request, StatusCode::OK));
+    }
+}
+
+mod ack_cancel {
+    use super::*;
+    use rsip::{message::HeadersExt, Method, Response};
+
+    fn invite() -> Request {
+        Request::try_from(concat!(
+            "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n",
+            "Via: SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+            "Max-Forwards: 70\r\n",
+            "Route: <sip:ss1.atlanta.example.com;lr>\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+            "CSeq: 2 INVITE\r\n",
+            "Contact: <sip:alice@client.atlanta.example.com;transport=tcp>\r\n",
+            "Content-Type: application/sdp\r\n",
+            "Content-Length: 4\r\n\r\n",
+            "v=0\n"
+        ))
+        .unwrap()
+    }
+
+    fn response(status: &str) -> Response {
+        Response::try_from(format!(
+            concat!(
+                "SIP/2.0 {}\r\n",
+                "Via: SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9;received=192.0.2.101\r\n",
+                "Record-Route: <sip:ss2.biloxi.example.com;lr>, <sip:ss1.atlanta.example.com;lr>\r\n",
+                "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+                "To: Bob <sip:bob@biloxi.example.com>;tag=314159\r\n",
+                "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+                "CSeq: 2 INVITE\r\n",
+                "Contact: <sip:bob@client.biloxi.example.com;transport=tcp>\r\n",
+                "Content-Length: 0\r\n\r\n"
+            ),
+            status
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn cancel() {
+        let cancel = invite().cancel().unwrap();
+
+        assert_eq!(cancel.method, Method::Cancel);
+        assert_eq!(cancel.uri, invite().uri);
+        assert_eq!(cancel.body, Vec::<u8>::new());
+        assert_eq!(
+            cancel.headers,
+            vec![
+                Via::new("SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9").into(),
+                Route::new("<sip:ss1.atlanta.example.com;lr>").into(),
+                MaxForwards::new("70").into(),
+                From::new("Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl").into(),
+                To::new("Bob <sip:bob@biloxi.example.com>").into(),
+                CallId::new("3848276298220188511@atlanta.example.com").into(),
+                CSeq::new("2 CANCEL").into(),
+                ContentLength::new("0").into(),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn cancel_non_invite() {
+        let mut request = invite();
+        request.method = Method::Bye;
+
+        assert!(request.cancel().is_err());
+    }
+
+    #[test]
+    fn ack_non_2xx() {
+        let ack = invite().ack(&response("486 Busy Here")).unwrap();
+
+        assert_eq!(ack.method, Method::Ack);
+        assert_eq!(ack.uri, invite().uri);
+        assert_eq!(
+            ack.headers,
+            vec![
+                Via::new("SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9").into(),
+                Route::new("<sip:ss1.atlanta.example.com;lr>").into(),
+                MaxForwards::new("70").into(),
+                From::new("Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl").into(),
+                To::new("Bob <sip:bob@biloxi.example.com>;tag=314159").into(),
+                CallId::new("3848276298220188511@atlanta.example.com").into(),
+                CSeq::new("2 ACK").into(),
+                ContentLength::new("0").into(),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn ack_2xx() {
+        let ack = invite().ack(&response("200 OK")).unwrap();
+
+        assert_eq!(ack.method, Method::Ack);
+        assert_eq!(
+            ack.uri,
+            uri::Uri::try_from("sip:bob@client.biloxi.example.com;transport=tcp").unwrap()
+        );
+        assert_eq!(
+            ack.route_header().unwrap().value(),
+            "<sip:ss1.atlanta.example.com;lr>,<sip:ss2.biloxi.example.com;lr>"
+        );
+        assert_ne!(
+            ack.via_header().unwrap().branch().unwrap().value(),
+            "z9hG4bK74bf9"
+        );
+        assert_eq!(ack.cseq_header().unwrap().value(), "2 ACK");
+        assert_eq!(
+            ack.to_header().unwrap().tag().unwrap().unwrap().value(),
+            "314159"
+        );
+        assert!(ack.contact_header().is_err());
+        assert_eq!(ack.content_length_header().unwrap().value(), "0");
+    }
+
+    #[test]
+    fn ack_2xx_with_credentials() {
+        let mut invite = invite();
+        let authorization = concat!(
+            "Digest username=\"alice\", realm=\"atlanta.example.com\", ",
+            "nonce=\"84f1c1ae6cbe5ua9c8e88dfa3ecm3459\", uri=\"sip:bob@biloxi.example.com\", ",
+            "response=\"6ed9a6c6e1e7ae2f6d2ef7bb0b27d1d6\""
+        );
+        invite
+            .headers
+            .push(Header::ProxyAuthorization(authorization.into()));
+        invite
+            .headers
+            .push(Header::Authorization(authorization.into()));
+
+        let ack = invite.ack(&response("200 OK")).unwrap();
+
+        assert_eq!(ack.authorization_header().unwrap().value(), authorization);
+        assert_eq!(
+            ack.headers.get_all("Proxy-Authorization"),
+            vec![&Header::ProxyAuthorization(authorization.into())]
+        );
+    }
+
+    #[test]
+    fn ack_provisional() {
+        assert!(invite().ack(&response("180 Ringing")).is_err());
+    }
+}
+
 mod parser {
     use super::*;
 
@@ -110,6 +110,39 @@ fn parser_loop() -> Result<(), rsip::Error> {
     Ok(())
 }
 
+#[test]
+fn folded_parser() -> Result<(), rsip::Error> {
+    use rsip::{message::HeadersExt, typed};
+
+    let resp: &str = concat!(
+        "SIP/2.0 401 Unauthorized\r\n",
+        "Via: SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashds7\r\n",
+        " ;received=192.0.2.201\r\n",
+        "From: Bob <sips:bob@biloxi.example.com>;tag=a73kszlfl\r\n",
+        "To: Bob <sips:bob@biloxi.example.com>;tag=1410948204\r\n",
+        "Call-ID: 1j9FpLxk3uxtm8tn@biloxi.example.com\r\n",
+        "CSeq: 1 REGISTER\r\n",
+        "WWW-Authenticate: Digest realm=\"atlanta.example.com\", qop=\"auth\",\r\n",
+        "\tnonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\",\r\n",
+        "\topaque=\"\", stale=FALSE, algorithm=MD5\r\n",
+        "Content-Length: 0\r\n\r\n"
+    );
+    let response = Response::try_from(resp)?;
+
+    assert_eq!(
+        response.via_header()?,
+        &Via::new("SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashds7 ;received=192.0.2.201")
+    );
+    let www_authenticate: typed::WwwAuthenticate = response
+        .www_authenticate_header()
+        .expect("www-authenticate header")
+        .typed()?;
+    assert_eq!(www_authenticate.nonce, "ea9c8e88df84f1cec4341ae6cbe5a359");
+    assert_eq!(www_authenticate.opaque, Some("".into()));
+
+    Ok(())
+}
+
 mod parser {
     use super::*;
 
@@ -257,3 +290,78 @@ mod tokenizer {
         assert!(Tokenizer::tokenize("REGISTER sip:server.com SIP/2.0\r\n\r\n".as_bytes()).is_err());
     }
 }
+
+mod challenges {
+    use super::*;
+    use rsip::headers::auth::{Algorithm, Qop};
+
+    fn response() -> Response {
+        Response::try_from(concat!(
+            "SIP/2.0 401 Unauthorized\r\n",
+            "Via: SIP/2.0/UDP bobspc.biloxi.com:5060;branch=z9hG4bKnashds7\r\n",
+            "To: Bob <sip:bob@biloxi.com>;tag=2493k59kd\r\n",
+            "From: Bob <sip:bob@biloxi.com>;tag=456248\r\n",
+            "Call-ID: 843817637684230@998sdasdh09\r\n",
+            "CSeq: 1826 REGISTER\r\n",
+            "WWW-Authenticate: Digest realm=\"biloxi.com\", qop=\"auth,auth-int\", ",
+            "algorithm=SHA-256, nonce=\"sha256nonce\", opaque=\"a,b\", ",
+            "Digest realm=\"biloxi.com\", qop=\"auth\", algorithm=MD5, nonce=\"md5nonce\"\r\n",
+            "WWW-Authenticate: Basic realm=\"biloxi.com\"\r\n",
+            "WWW-Authenticate: Digest realm=\"other.com\", nonce=\"othernonce\"\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn www_authenticate_challenges() {
+        let challenges = response().www_authenticate_challenges();
+
+        assert_eq!(challenges.len(), 3);
+        assert_eq!(challenges[0].nonce, "sha256nonce");
+        assert_eq!(challenges[0].algorithm, Some(Algorithm::Sha256));
+        assert_eq!(challenges[0].qop, vec![Qop::Auth, Qop::AuthInt]);
+        assert_eq!(challenges[0].opaque, Some("a,b".into()));
+        assert_eq!(challenges[1].nonce, "md5nonce");
+        assert_eq!(challenges[1].algorithm, Some(Algorithm::Md5));
+        assert_eq!(challenges[1].qop, vec![Qop::Auth]);
+        assert_eq!(challenges[2].realm, "other.com");
+        assert!(challenges[2].qop.is_empty());
+
+        assert!(response().proxy_authenticate_challenges().is_empty());
+    }
+
+    #[test]
+    fn proxy_authenticate_challenges() {
+        let mut response = response();
+        response.status_code = rsip::StatusCode::ProxyAuthenticationRequired;
+        response.headers.push(Header::ProxyAuthenticate(
+            "Digest realm=\"atlanta.com\", nonce=\"proxynonce\", qop=\"auth\"".into(),
+        ));
+
+        let challenges = response.proxy_authenticate_challenges();
+        assert_eq!(challenges.len(), 1);
+        assert_eq!(challenges[0].0.nonce, "proxynonce");
+    }
+
+    #[test]
+    fn escaped_quote_challenges() {
+        let mut response = response();
+        response
+            .headers
+            .retain(|header| !matches!(header, Header::WwwAuthenticate(_)));
+        response.headers.push(Header::WwwAuthenticate(
+            concat!(
+                "Digest realm=\"a\\\"b\", nonce=\"n1\", ",
+                "Digest realm=\"c\", nonce=\"n2\""
+            )
+            .into(),
+        ));
+
+        let challenges = response.www_authenticate_challenges();
+        assert_eq!(challenges.len(), 2);
+        assert_eq!(challenges[0].realm, "a\\\"b");
+        assert_eq!(challenges[0].nonce, "n1");
+        assert_eq!(challenges[1].nonce, "n2");
+    }
+}
@@ -0,0 +1,167 @@
+use rsip::{
+    headers::HeaderRef,
+    message::{HeadersRefExt, RequestRef, ResponseRef, SipMessageRef},
+    Header, Method, Request, Response, SipMessage, StatusCode, Uri, Version,
+};
+use std::convert::TryFrom;
+
+const REQUEST: &str = concat!(
+    "REGISTER sips:ss2.biloxi.example.com SIP/2.0\r\n",
+    "Via: SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashds7\r\n",
+    "v: SIP/2.0/UDP proxy.biloxi.example.com;branch=z9hG4bK74bf9\r\n",
+    "Max-Forwards: 70\r\n",
+    "From: Bob <sips:bob@biloxi.example.com>;tag=a73kszlfl\r\n",
+    "To: Bob <sips:bob@biloxi.example.com>\r\n",
+    "i: 1j9FpLxk3uxtm8tn@biloxi.example.com\r\n",
+    "CSeq: 1 REGISTER\r\n",
+    "Contact: <sips:bob@client.biloxi.example.com>\r\n",
+    "X-Custom: folded\r\n",
+    " value\r\n",
+    "Content-Length: 4\r\n\r\n",
+    "body"
+);
+
+#[test]
+fn request() {
+    let request = RequestRef::try_from(REQUEST.as_bytes()).unwrap();
+
+    assert_eq!(request.method(), &Method::Register);
+    assert_eq!(
+        request.uri().unwrap(),
+        Uri::try_from("sips:ss2.biloxi.example.com").unwrap()
+    );
+    assert_eq!(request.version(), &Version::V2);
+    assert_eq!(request.body(), b"body");
+    assert_eq!(request.headers().len(), 10);
+
+    assert_eq!(
+        request.via_header().unwrap().value().unwrap(),
+        "SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashds7"
+    );
+    assert_eq!(request.via_headers().len(), 2);
+    assert_eq!(
+        request.max_forwards_header().unwrap().value().unwrap(),
+        "70"
+    );
+    assert_eq!(
+        request.call_id_header().unwrap().value().unwrap(),
+        "1j9FpLxk3uxtm8tn@biloxi.example.com"
+    );
+    assert_eq!(request.call_id_header().unwrap().name().unwrap(), "i");
+    assert_eq!(request.content_length_header().unwrap().raw_value(), b"4");
+    assert!(request.route_header().is_none());
+    assert!(request.user_agent_header().is_none());
+}
+
+#[test]
+fn header_ref() {
+    let request = RequestRef::try_from(REQUEST.as_bytes()).unwrap();
+    let header = request.headers()[8];
+
+    assert!(header.is("x-custom", None));
+    assert!(!header.is("X-Other", Some("x")));
+    assert_eq!(header.value().unwrap(), "folded value");
+    assert_eq!(header.raw_value(), b"folded\r\n value");
+    assert_eq!(header.to_string(), "X-Custom: folded\r\n value");
+    assert_eq!(
+        Header::try_from(header),
+        Ok(Header::Other("X-Custom".into(), "folded value".into()))
+    );
+    assert!(request.headers()[1].is("Via", Some("v")));
+}
+
+#[test]
+fn into_owned() {
+    let request = RequestRef::try_from(REQUEST.as_bytes()).unwrap();
+    assert_eq!(
+        Request::try_from(request),
+        Request::try_from(REQUEST.as_bytes())
+    );
+
+    let message = SipMessageRef::try_from(REQUEST).unwrap();
+    assert!(message.is_request());
+    assert_eq!(message.body(), b"body");
+    assert_eq!(SipMessage::try_from(message), SipMessage::try_from(REQUEST));
+}
+
+#[test]
+fn response() {
+    let input = concat!(
+        "SIP/2.0 401 Unauthorized\r\n",
+        "Via: SIP/2.0/TLS client.biloxi.example.com:5061;branch=z9hG4bKnashds7\r\n",
+        "WWW-Authenticate: Digest realm=\"atlanta.example.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\"\r\n",
+        "Content-Length: 0\r\n\r\n"
+    );
+    let response = ResponseRef::try_from(input.as_bytes()).unwrap();
+
+    assert_eq!(response.status_code(), &StatusCode::Unauthorized);
+    assert!(response.body().is_empty());
+    assert!(response.www_authenticate_header().is_some());
+    assert!(response.to_header().is_err());
+    assert_eq!(Response::try_from(response), Response::try_from(input));
+
+    let message = SipMessageRef::try_from(input).unwrap();
+    assert!(message.is_response());
+    assert_eq!(message.version(), &Version::V2);
+    assert_eq!(message.headers().len(), 3);
+}
+
+#[test]
+fn invalid_utf8() {
+    let mut input = b"SIP/2.0 200 OK\r\nSubject: ".to_vec();
+    input.extend_from_slice(&[0xff, 0xfe]);
+    input.extend_from_slice(b"\r\nContent-Length: 0\r\n\r\n");
+
+    let message = SipMessageRef::try_from(input.as_slice()).unwrap();
+    let subject: HeaderRef = message.headers()[0];
+    assert!(subject.value().is_err());
+    assert!(SipMessage::try_from(message).is_err());
+}
+
+#[test]
+fn errors() {
+    assert!(SipMessageRef::try_from("REGISTE sip:bob@biloxi.com SIP/2.0\r\n\r\n").is_err());
+    assert!(ResponseRef::try_from(REQUEST.as_bytes()).is_err());
+}
+
+#[test]
+fn compact_names_match_owned_headers() {
+    let response = ResponseRef::try_from(
+        concat!(
+            "SIP/2.0 200 OK\r\n",
+            "v: SIP/2.0/UDP proxy.biloxi.example.com;branch=z9hG4bK74bf9\r\n",
+            "f: Bob <sips:bob@biloxi.example.com>;tag=a73kszlfl\r\n",
+            "t: Bob <sips:bob@biloxi.example.com>;tag=8321234356\r\n",
+            "i: 1j9FpLxk3uxtm8tn@biloxi.example.com\r\n",
+            "CSeq: 1 REGISTER\r\n",
+            "m: <sips:bob@client.biloxi.example.com>\r\n",
+            "l: 0\r\n\r\n",
+        )
+        .as_bytes(),
+    )
+    .unwrap();
+
+    let found = vec![
+        response.via_header().unwrap(),
+        response.from_header().unwrap(),
+        response.to_header().unwrap(),
+        response.call_id_header().unwrap(),
+        response.contact_header().unwrap(),
+        response.content_length_header().unwrap(),
+    ];
+    let owned = found
+        .into_iter()
+        .map(|header| Header::try_from(header).unwrap())
+        .collect::<Vec<_>>();
+    assert!(matches!(
+        owned.as_slice(),
+        [
+            Header::Via(_),
+            Header::From(_),
+            Header::To(_),
+            Header::CallId(_),
+            Header::Contact(_),
+            Header::ContentLength(_)
+        ]
+    ));
+}
@@ -0,0 +1,202 @@
+use rsip::{
+    headers::*,
+    message::{Decoded, StreamDecoder},
+    Response, SipMessage,
+};
+use std::convert::TryFrom;
+
+const INVITE: &str = concat!(
+    "INVITE sip:bob@biloxi.com SIP/2.0\r\n",
+    "Via: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "To: Bob <sip:bob@biloxi.com>\r\n",
+    "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "Content-Length: 13\r\n\r\n",
+    "v=0\r\n",
+    "s=call\r\n"
+);
+
+const OK: &str = concat!(
+    "SIP/2.0 200 OK\r\n",
+    "Via: SIP/2.0/TCP pc33.atlanta.com;branch=z9hG4bK776asdhds\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "l: 0\r\n\r\n",
+);
+
+#[test]
+fn single_message() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new();
+    decoder.feed(INVITE.as_bytes());
+
+    assert_eq!(
+        decoder.decode()?,
+        Decoded::Message(SipMessage::try_from(INVITE)?)
+    );
+    assert_eq!(decoder.buffer(), b"");
+    assert_eq!(decoder.decode()?, Decoded::Incomplete);
+
+    Ok(())
+}
+
+#[test]
+fn byte_by_byte() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new();
+    let mut messages = vec![];
+
+    for byte in INVITE.as_bytes().iter().chain(OK.as_bytes()) {
+        decoder.feed(&[*byte]);
+        if let Decoded::Message(message) = decoder.decode()? {
+            messages.push(message);
+        }
+    }
+
+    assert_eq!(
+        messages,
+        vec![SipMessage::try_from(INVITE)?, SipMessage::try_from(OK)?]
+    );
+    assert_eq!(decoder.buffer(), b"");
+
+    Ok(())
+}
+
+#[test]
+fn many_messages_and_leftover() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new();
+    decoder.feed(format!("{}{}SIP/2.0 18", INVITE, OK).as_bytes());
+
+    assert_eq!(
+        decoder.decode()?,
+        Decoded::Message(SipMessage::try_from(INVITE)?)
+    );
+    assert_eq!(
+        decoder.decode()?,
+        Decoded::Message(SipMessage::try_from(OK)?)
+    );
+    assert_eq!(decoder.decode()?, Decoded::Incomplete);
+    assert_eq!(decoder.buffer(), b"SIP/2.0 18");
+
+    Ok(())
+}
+
+#[test]
+fn body_is_not_parsed_as_next_message() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new();
+    decoder.feed(&INVITE.as_bytes()[..INVITE.len() - 5]);
+
+    assert_eq!(decoder.decode()?, Decoded::Incomplete);
+    assert_eq!(decoder.buffer(), b"v=0\r\ns=c");
+
+    decoder.feed(&INVITE.as_bytes()[INVITE.len() - 5..]);
+    match decoder.decode()? {
+        Decoded::Message(message) => assert_eq!(message.body(), b"v=0\r\ns=call\r\n"),
+        Decoded::Incomplete => panic!("message should be complete"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn keep_alives() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new();
+    decoder.feed(b"\r\n\r\n");
+    assert_eq!(decoder.decode()?, Decoded::Incomplete);
+    assert_eq!(decoder.buffer(), b"");
+
+    decoder.feed(format!("\r\n\r\n{}\r\n", OK).as_bytes());
+    assert_eq!(
+        decoder.decode()?,
+        Decoded::Message(SipMessage::try_from(OK)?)
+    );
+    assert_eq!(decoder.decode()?, Decoded::Incomplete);
+    assert_eq!(decoder.buffer(), b"");
+
+    Ok(())
+}
+
+#[test]
+fn missing_content_length() {
+    let mut decoder = StreamDecoder::new();
+    let response = Response {
+        status_code: 200.into(),
+        headers: vec![CallId::new("a84b4c76e66710@pc33.atlanta.com").into()].into(),
+        ..Default::default()
+    };
+    decoder.feed(response.to_string().as_bytes());
+
+    assert_eq!(
+        decoder.decode(),
+        Err(rsip::Error::MissingHeader("Content-Length".into()))
+    );
+}
+
+#[test]
+fn recovers_after_malformed_message() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new();
+    decoder.feed(b"NOT A SIP MESSAGE\r\nContent-Length: 0\r\n\r\n");
+    decoder.feed(OK.as_bytes());
+
+    assert!(decoder.decode().is_err());
+    assert_eq!(decoder.buffer(), OK.as_bytes());
+    assert_eq!(
+        decoder.decode()?,
+        Decoded::Message(SipMessage::try_from(OK)?)
+    );
+    assert_eq!(decoder.buffer(), b"");
+
+    Ok(())
+}
+
+#[test]
+fn head_end_split_across_feeds() -> Result<(), rsip::Error> {
+    for split in OK.len() - 5..OK.len() {
+        let mut decoder = StreamDecoder::new();
+        decoder.feed(&OK.as_bytes()[..split]);
+        assert_eq!(decoder.decode()?, Decoded::Incomplete);
+
+        decoder.feed(&OK.as_bytes()[split..]);
+        assert_eq!(
+            decoder.decode()?,
+            Decoded::Message(SipMessage::try_from(OK)?)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn head_too_long() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new().with_max_head_len(OK.len());
+    decoder.feed(&[b'a'; 64]);
+    assert_eq!(decoder.decode()?, Decoded::Incomplete);
+
+    decoder.feed(INVITE.as_bytes());
+    assert!(decoder.decode().is_err());
+    assert_eq!(decoder.buffer(), b"");
+
+    decoder.feed(OK.as_bytes());
+    assert_eq!(
+        decoder.decode()?,
+        Decoded::Message(SipMessage::try_from(OK)?)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn body_too_long() -> Result<(), rsip::Error> {
+    let mut decoder = StreamDecoder::new().with_max_body_len(12);
+    decoder.feed(INVITE.as_bytes());
+
+    assert_eq!(
+        decoder.decode(),
+        Err(rsip::Error::ParseError(
+            "message body exceeds 12 bytes".into()
+        ))
+    );
+    assert_eq!(decoder.buffer(), b"v=0\r\ns=call\r\n");
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use rsip::{
+    message::{transaction_key::TransactionKey, Request, Response},
+    param::Branch,
+    Method,
+};
+use std::{collections::HashMap, convert::TryFrom};
+
+fn request(method: &str, via: &str, to: &str) -> Request {
+    Request::try_from(format!(
+        concat!(
+            "{method} sip:bob@biloxi.com SIP/2.0\r\n",
+            "Via: {via}\r\n",
+            "Max-Forwards: 70\r\n",
+            "To: {to}\r\n",
+            "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 {method}\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        method = method,
+        via = via,
+        to = to
+    ))
+    .unwrap()
+}
+
+fn response(status: &str, method: &str, via: &str) -> Response {
+    Response::try_from(format!(
+        concat!(
+            "SIP/2.0 {status}\r\n",
+            "Via: {via}\r\n",
+            "To: Bob <sip:bob@biloxi.com>;tag=a6c85cf\r\n",
+            "From: Alice <sip:alice@atlanta.com>;tag=1928301774\r\n",
+            "Call-ID: a84b4c76e66710@pc33.atlanta.com\r\n",
+            "CSeq: 314159 {method}\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        status = status,
+        method = method,
+        via = via
+    ))
+    .unwrap()
+}
+
+const VIA: &str = "SIP/2.0/UDP pc33.atlanta.com;branch=z9hG4bK776asdhds";
+const TO: &str = "Bob <sip:bob@biloxi.com>";
+
+#[test]
+fn client() {
+    let key = TransactionKey::client(&request("INVITE", VIA, TO)).unwrap();
+
+    assert_eq!(
+        key,
+        TransactionKey::Client {
+            branch: Branch::new("z9hG4bK776asdhds"),
+            method: Method::Invite
+        }
+    );
+    assert_eq!(
+        TransactionKey::client(&response("180 Ringing", "INVITE", VIA)),
+        Ok(key.clone())
+    );
+    assert_ne!(
+        TransactionKey::client(&response("200 OK", "CANCEL", VIA)),
+        Ok(key)
+    );
+}
+
+#[test]
+fn server() {
+    let key = TransactionKey::server(&request("INVITE", VIA, TO)).unwrap();
+
+    assert_eq!(
+        key,
+        TransactionKey::Server {
+            branch: Branch::new("z9hG4bK776asdhds"),
+            sent_by: ("pc33.atlanta.com", Option::<u16>::None).into(),
+            method: Method::Invite
+        }
+    );
+    assert!(!key.is_rfc2543());
+    assert_eq!(
+        TransactionKey::server(&request("ACK", VIA, "Bob <sip:bob@biloxi.com>;tag=a6c85cf")),
+        Ok(key.clone())
+    );
+    assert_ne!(
+        TransactionKey::server(&request("CANCEL", VIA, TO)),
+        Ok(key.clone())
+    );
+    assert_ne!(
+        TransactionKey::server(&request(
+            "INVITE",
+            "SIP/2.0/UDP pc33.atlanta.com:5070;branch=z9hG4bK776asdhds",
+            TO
+        )),
+        Ok(key.clone())
+    );
+    assert_eq!(
+        TransactionKey::server_from_response(&response("180 Ringing", "INVITE", VIA)),
+        Ok(key)
+    );
+}
+
+#[test]
+fn rfc2543() {
+    let via = "SIP/2.0/UDP pc33.atlanta.com;branch=776asdhds";
+    let key = TransactionKey::server(&request("INVITE", via, TO)).unwrap();
+
+    assert!(key.is_rfc2543());
+    assert_eq!(
+        TransactionKey::server(&request(
+            "INVITE",
+            "SIP/2.0/udp  PC33.Atlanta.com;BRANCH=776asdhds",
+            TO
+        )),
+        Ok(key.clone())
+    );
+    let ack = TransactionKey::server(&request("ACK", via, "Bob <sip:bob@biloxi.com>;tag=a6c85cf"))
+        .unwrap();
+    assert_ne!(ack, key);
+    assert_eq!(
+        key.with_response_to_tag(&response("486 Busy Here", "INVITE", via)),
+        Ok(ack)
+    );
+    assert_ne!(
+        TransactionKey::server(&request(
+            "INVITE",
+            via,
+            "Bob <sip:bob@biloxi.com>;tag=a6c85cf"
+        )),
+        Ok(key.clone())
+    );
+    assert_ne!(
+        TransactionKey::server(&request("BYE", via, "Bob <sip:bob@biloxi.com>;tag=a6c85cf")),
+        Ok(key.clone())
+    );
+    assert_ne!(
+        TransactionKey::server(&request(
+            "INVITE",
+            "SIP/2.0/UDP pc34.atlanta.com;branch=776asdhds",
+            TO
+        )),
+        Ok(key)
+    );
+    assert!(TransactionKey::server_from_response(&response("180 Ringing", "INVITE", via)).is_err());
+}
+
+#[test]
+fn hash_map_key() {
+    let mut transactions = HashMap::new();
+    transactions.insert(
+        TransactionKey::server(&request("INVITE", VIA, TO)).unwrap(),
+        "invite transaction",
+    );
+
+    assert_eq!(
+        transactions.get(
+            &TransactionKey::server(&request("ACK", VIA, "Bob <sip:bob@biloxi.com>;tag=a6c85cf"))
+                .unwrap()
+        ),
+        Some(&"invite transaction")
+    );
+}
@@ -0,0 +1,168 @@
+use rsip::{
+    message::{Request, Response, Violation},
+    Method, SipMessage,
+};
+use std::convert::TryFrom;
+
+fn request(headers: &str, body: &str) -> Request {
+    Request::try_from(format!(
+        "INVITE sip:bob@biloxi.example.com SIP/2.0\r\n{}\r\n{}",
+        headers, body
+    ))
+    .unwrap()
+}
+
+const HEADERS: &str = concat!(
+    "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+    "Max-Forwards: 70\r\n",
+    "To: Bob <sip:bob@biloxi.example.com>\r\n",
+    "From: Alice <sip:alice@atlanta.example.com>;tag=1928301774\r\n",
+    "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+    "CSeq: 314159 INVITE\r\n",
+    "Content-Length: 4\r\n"
+);
+
+#[test]
+fn valid() {
+    assert_eq!(request(HEADERS, "v=0\n").validate(), vec![]);
+    assert_eq!(
+        SipMessage::from(request(HEADERS, "v=0\n")).validate(),
+        vec![]
+    );
+}
+
+#[test]
+fn missing_headers() {
+    let request = request(
+        concat!(
+            "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "CSeq: 314159 INVITE\r\n",
+        ),
+        "",
+    );
+
+    assert_eq!(
+        request.validate(),
+        vec![
+            Violation::MissingHeader("From".into()),
+            Violation::MissingHeader("Call-ID".into()),
+            Violation::MissingHeader("Max-Forwards".into()),
+        ]
+    );
+}
+
+#[test]
+fn invalid_header_is_not_a_name() {
+    let mut request = request(HEADERS, "v=0\n");
+    request
+        .headers
+        .retain(|header| !matches!(header, rsip::Header::From(_)));
+    request.headers.push(rsip::Header::Invalid("From".into()));
+
+    assert_eq!(
+        request.validate(),
+        vec![Violation::MissingHeader("From".into())]
+    );
+}
+
+#[test]
+fn response_without_max_forwards() {
+    let response = Response::try_from(concat!(
+        "SIP/2.0 200 OK\r\n",
+        "Via: SIP/2.0/UDP pc33.atlanta.example.com;branch=z9hG4bK776asdhds\r\n",
+        "To: Bob <sip:bob@biloxi.example.com>;tag=a6c85cf\r\n",
+        "From: Alice <sip:alice@atlanta.example.com>;tag=1928301774\r\n",
+        "Call-ID: a84b4c76e66710@pc33.atlanta.example.com\r\n",
+        "CSeq: 314159 INVITE\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))
+    .unwrap();
+
+    assert_eq!(response.validate(), vec![]);
+}
+
+#[test]
+fn cseq_method_mismatch() {
+    let request = request(&HEADERS.replace("314159 INVITE", "314159 BYE"), "v=0\n");
+
+    assert_eq!(
+        request.validate(),
+        vec![Violation::CSeqMethodMismatch {
+            method: Method::Invite,
+            cseq: Method::Bye
+        }]
+    );
+}
+
+#[test]
+fn duplicate_headers() {
+    let request = request(
+        &format!(
+            "{}{}{}",
+            HEADERS,
+            "To: Carol <sip:carol@chicago.example.com>\r\n",
+            "Content-Length: 4\r\nVia: SIP/2.0/UDP p1.example.com;branch=z9hG4bK1\r\n"
+        ),
+        "v=0\n",
+    );
+
+    assert_eq!(
+        request.validate(),
+        vec![
+            Violation::DuplicateHeader("To".into()),
+            Violation::DuplicateHeader("Content-Length".into()),
+        ]
+    );
+}
+
+#[test]
+fn content_length_mismatch() {
+    assert_eq!(
+        request(HEADERS, "v=0\r\no=-\r\n").validate(),
+        vec![Violation::ContentLengthMismatch {
+            content_length: 4,
+            body: 10
+        }]
+    );
+}
+
+#[test]
+fn max_forwards() {
+    assert_eq!(
+        request(
+            &HEADERS.replace("Max-Forwards: 70", "Max-Forwards: 256"),
+            "v=0\n"
+        )
+        .validate(),
+        vec![Violation::MaxForwardsOutOfRange(256)]
+    );
+
+    let violations = request(
+        &HEADERS.replace("Max-Forwards: 70", "Max-Forwards: many"),
+        "v=0\n",
+    )
+    .validate();
+    assert!(matches!(
+        violations.as_slice(),
+        [Violation::InvalidHeader(name, _)] if name == "Max-Forwards"
+    ));
+}
+
+#[test]
+fn invalid_typed_headers() {
+    let request = request(
+        &HEADERS
+            .replace("Via: SIP/2.0/UDP", "Via: SIP/2.0 UDP")
+            .replace("CSeq: 314159 INVITE", "CSeq: INVITE"),
+        "v=0\n",
+    );
+
+    let violations = request.validate();
+    assert_eq!(violations.len(), 2);
+    assert!(matches!(&violations[0], Violation::InvalidHeader(name, _) if name == "Via"));
+    assert!(matches!(&violations[1], Violation::InvalidHeader(name, _) if name == "CSeq"));
+    assert!(violations[0]
+        .to_string()
+        .starts_with("invalid header Via: "));
+}
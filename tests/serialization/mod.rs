@@ -0,0 +1,94 @@
+use rsip::{
+    common::uri::{self, Uri},
+    headers::{self, untyped::ToTypedHeader, UntypedHeader},
+    Request, Response, SipMessage,
+};
+use std::convert::TryFrom;
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).expect("serialize");
+    serde_json::from_str(&json).expect("deserialize")
+}
+
+#[test]
+fn request() {
+    let request = Request::try_from(concat!(
+        "INVITE sip:bob@biloxi.example.com:5060;transport=tcp SIP/2.0\r\n",
+        "Via: SIP/2.0/TCP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+        "Max-Forwards: 70\r\n",
+        "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+        "To: Bob <sip:bob@biloxi.example.com>\r\n",
+        "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+        "CSeq: 1 INVITE\r\n",
+        "X-Custom: something\r\n",
+        "Content-Length: 4\r\n\r\n",
+        "test"
+    ))
+    .unwrap();
+
+    assert_eq!(round_trip(&request), request);
+}
+
+#[test]
+fn response() {
+    let response = Response::try_from(concat!(
+        "SIP/2.0 401 Unauthorized\r\n",
+        "Via: SIP/2.0/UDP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+        "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+        "To: Bob <sip:bob@biloxi.example.com>;tag=8321234356\r\n",
+        "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+        "CSeq: 1 REGISTER\r\n",
+        "WWW-Authenticate: Digest realm=\"atlanta.example.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", algorithm=MD5, qop=\"auth\"\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))
+    .unwrap();
+
+    assert_eq!(round_trip(&response), response);
+
+    let message: SipMessage = response.into();
+    assert_eq!(round_trip(&message), message);
+}
+
+#[test]
+fn uri_is_structured() {
+    let uri = Uri::try_from("sips:alice:secret@atlanta.example.com:5061;transport=tls").unwrap();
+
+    let value = serde_json::to_value(&uri).unwrap();
+    assert_eq!(value["scheme"], serde_json::json!("Sips"));
+    assert_eq!(value["auth"]["user"], serde_json::json!("alice"));
+    assert_eq!(value["host_with_port"]["port"], serde_json::json!(5061));
+    assert_eq!(value["params"][0]["Transport"], serde_json::json!("Tls"));
+
+    assert_eq!(serde_json::from_value::<Uri>(value).unwrap(), uri);
+}
+
+#[test]
+fn typed_headers() {
+    let via = headers::Via::new(
+        "SIP/2.0/UDP 10.0.0.1:5060;branch=z9hG4bK-524287-1---1cf2a5fd8f6e6b02;rport",
+    )
+    .typed()
+    .unwrap();
+    assert_eq!(round_trip(&via), via);
+
+    let from = headers::From::new("\"Alice\" <sip:alice@atlanta.example.com>;tag=1928301774")
+        .typed()
+        .unwrap();
+    assert_eq!(round_trip(&from), from);
+
+    let authorization = headers::Authorization::new(concat!(
+        "Digest username=\"bob\", realm=\"atlanta.example.com\", ",
+        "nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", uri=\"sips:ss2.biloxi.example.com\", ",
+        "response=\"dfe56131d1958046689d83306477ecc\", algorithm=MD5, ",
+        "qop=auth, cnonce=\"0a4f113b\", nc=00000001"
+    ))
+    .typed()
+    .unwrap();
+    assert_eq!(round_trip(&authorization), authorization);
+
+    let host = uri::Host::from(std::net::IpAddr::from([127, 0, 0, 1]));
+    assert_eq!(round_trip(&host), host);
+}
@@ -1,6 +1,6 @@
 use rsip::{
     common::{
-        uri::{Host, HostWithPort},
+        uri::{Host, HostWithPort, Uri},
         Method,
     },
     headers::auth::{Algorithm, AuthQop},
@@ -25,8 +25,156 @@ fn digest() {
         qop: Some(&auth_qop),
         uri: &uri.into(),
         realm: "testrealm@host.com",
+        body: &[],
     };
 
     assert_eq!("59d17b90f0e821045ecceb843e5b38c4", generator.compute());
     assert_eq!(generator.verify("59d17b90f0e821045ecceb843e5b38c4"), true);
 }
+
+mod rfc7616 {
+    use super::*;
+
+    //the example of RFC7616 section 3.9.1, using REGISTER instead of GET (which is not a SIP
+    //method), thus the responses differ from the ones of the RFC
+    fn generator<'a>(algorithm: Algorithm, qop: &'a AuthQop, uri: &'a Uri) -> DigestGenerator<'a> {
+        DigestGenerator {
+            username: "Mufasa",
+            password: "Circle of Life",
+            algorithm,
+            nonce: "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v",
+            method: &Method::Register,
+            qop: Some(qop),
+            uri,
+            realm: "http-auth@example.org",
+            body: &[],
+        }
+    }
+
+    fn uri() -> Uri {
+        HostWithPort::from(Host::from("/dir/index.html")).into()
+    }
+
+    fn auth() -> AuthQop {
+        AuthQop::Auth {
+            cnonce: "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ".into(),
+            nc: 1,
+        }
+    }
+
+    fn auth_int() -> AuthQop {
+        AuthQop::AuthInt {
+            cnonce: "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ".into(),
+            nc: 1,
+        }
+    }
+
+    #[test]
+    fn md5() {
+        assert_eq!(
+            generator(Algorithm::Md5, &auth(), &uri()).compute(),
+            "729aa9919ff68ad8e193d08df00a0a44"
+        );
+    }
+
+    #[test]
+    fn sha256() {
+        assert_eq!(
+            generator(Algorithm::Sha256, &auth(), &uri()).compute(),
+            "2b3883b3cfcef44adfad48d9d29d598196bffbefa9df3c5d339e795fd2f7302f"
+        );
+    }
+
+    #[test]
+    fn md5_sess() {
+        assert_eq!(
+            generator(Algorithm::Md5Sess, &auth(), &uri()).compute(),
+            "1780f7f6f8875043e6693b062f375c48"
+        );
+    }
+
+    #[test]
+    fn sha256_sess() {
+        assert_eq!(
+            generator(Algorithm::Sha256Sess, &auth(), &uri()).compute(),
+            "8dfaf5c3e9ab9f83c8e20b1c8460b5da472dbd6f5575b03430dee683948084a4"
+        );
+    }
+
+    #[test]
+    fn auth_int_hashes_body() {
+        let qop = auth_int();
+
+        assert_eq!(
+            generator(Algorithm::Md5, &qop, &uri())
+                .with_body(b"v=0\r\n")
+                .compute(),
+            "889348cdfde49b6f0f1f8da6e058e862"
+        );
+        assert_eq!(
+            generator(Algorithm::Sha256, &qop, &uri())
+                .with_body(b"v=0\r\n")
+                .compute(),
+            "7d072bdef067455dd23491a87d22e8b0d949cc30ec6cd57fe8d864b73bcabfb8"
+        );
+        assert_eq!(
+            generator(Algorithm::Sha256Sess, &qop, &uri())
+                .with_body(b"v=0\r\n")
+                .compute(),
+            "db84d7b52bf08a0af07571914370168e440f3d33dee0b2cf7a8b2ca20d7bbbdd"
+        );
+        assert_eq!(
+            generator(Algorithm::Md5, &qop, &uri()).compute(),
+            "494fe1150612d7fbba1be2186da320df"
+        );
+    }
+
+    #[test]
+    fn compute_with_ha1() {
+        let qop = auth();
+
+        assert_eq!(
+            generator(Algorithm::Md5Sess, &qop, &uri())
+                .compute_with_ha1("3d78807defe7de2157e2b0b6573a855f"),
+            "1780f7f6f8875043e6693b062f375c48"
+        );
+        assert_eq!(
+            generator(Algorithm::Sha256, &qop, &uri()).compute_with_ha1(
+                "7987c64c30e25f1b74be53f966b49b90f2808aa92faf9a00262392d7b4794232"
+            ),
+            "2b3883b3cfcef44adfad48d9d29d598196bffbefa9df3c5d339e795fd2f7302f"
+        );
+    }
+
+    //the example of RFC7616 section 3.9.2 (again using REGISTER instead of GET), where the
+    //userhash printed in the RFC doesn't match SHA-512-256 (see the errata of the RFC), so it's
+    //computed independently
+    #[test]
+    fn sha512_256_with_userhash() {
+        let uri = HostWithPort::from(Host::from("/doe.json")).into();
+        let qop = AuthQop::Auth {
+            cnonce: "NTg6RKcb9boFIAS3KrFK9BGeh+iDa/sm6jUMp2wds69v".into(),
+            nc: 1,
+        };
+        let generator = DigestGenerator {
+            username: "J\u{e4}s\u{f8}n Doe",
+            password: "Secret, or not?",
+            algorithm: Algorithm::Sha512256,
+            nonce: "5TsQWLVdgBdmrQ0XsxbDODV+57QdFR34I9HAbC/RVvkK",
+            method: &Method::Register,
+            qop: Some(&qop),
+            uri: &uri,
+            realm: "api@example.org",
+            body: &[],
+        };
+
+        assert_eq!(
+            generator.userhash(),
+            "793263caabb707a56211940d90411ea4a575adeccb7e360aeb624ed06ece9b0b"
+        );
+        assert_eq!(
+            generator.compute(),
+            "9228976e82eb3141b93fb1604608f7fa243fadc7c03684b9b3f7bfbc3933cc43"
+        );
+    }
+}
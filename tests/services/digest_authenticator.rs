@@ -0,0 +1,236 @@
+use rsip::{
+    headers::{
+        auth::{Algorithm, Qop},
+        untyped::ToTypedHeader,
+    },
+    message::HeadersExt,
+    services::{Credential, DigestAuthenticator, DigestClient, Rejection},
+    Header, Request, StatusCode,
+};
+use std::{
+    convert::TryFrom,
+    time::{Duration, SystemTime},
+};
+
+fn register() -> Request {
+    Request::try_from(concat!(
+        "REGISTER sip:biloxi.com SIP/2.0\r\n",
+        "Via: SIP/2.0/UDP bobspc.biloxi.com:5060;branch=z9hG4bKnashds7\r\n",
+        "Max-Forwards: 70\r\n",
+        "To: Bob <sip:bob@biloxi.com>\r\n",
+        "From: Bob <sip:bob@biloxi.com>;tag=456248\r\n",
+        "Call-ID: 843817637684230@998sdasdh09\r\n",
+        "CSeq: 1826 REGISTER\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))
+    .unwrap()
+}
+
+fn credentials(username: &str, _realm: &str) -> Option<Credential> {
+    match username {
+        "bob" => Some(Credential::Password("zanzibar".into())),
+        _ => None,
+    }
+}
+
+fn authenticator() -> DigestAuthenticator<fn(&str, &str) -> Option<Credential>> {
+    DigestAuthenticator::new("biloxi.com", "secret", credentials as fn(&str, &str) -> _)
+}
+
+#[test]
+fn authenticate() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator();
+    let request = register();
+
+    let unauthorized = authenticator.authenticate(&request, now).unwrap_err();
+    assert_eq!(unauthorized.status_code, StatusCode::Unauthorized);
+    let challenge = unauthorized
+        .www_authenticate_header()
+        .unwrap()
+        .typed()
+        .unwrap();
+    assert_eq!(challenge.realm, "biloxi.com");
+    assert_eq!(challenge.stale, None);
+
+    let mut client = DigestClient::new("bob", "zanzibar");
+    let request = client.authorize(&request, &unauthorized).unwrap();
+    assert_eq!(
+        authenticator.authenticate(&request, now),
+        Ok(String::from("bob"))
+    );
+
+    let request = client.authorize(&request, &unauthorized).unwrap();
+    assert_eq!(
+        authenticator.verify(&request, now + Duration::from_secs(10)),
+        Ok(String::from("bob"))
+    );
+}
+
+#[test]
+fn authenticate_with_ha1() {
+    use md5::{Digest, Md5};
+
+    let now = SystemTime::now();
+    let ha1 = format!("{:x}", Md5::digest(b"bob:biloxi.com:zanzibar"));
+    let mut authenticator = DigestAuthenticator::new("biloxi.com", "secret", |_: &str, _: &str| {
+        Some(Credential::Ha1(ha1.clone()))
+    });
+
+    let unauthorized = authenticator.authenticate(&register(), now).unwrap_err();
+    let request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+    assert_eq!(authenticator.verify(&request, now), Ok(String::from("bob")));
+}
+
+#[test]
+fn replay() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator();
+
+    let unauthorized = authenticator.authenticate(&register(), now).unwrap_err();
+    let request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+
+    assert!(authenticator.verify(&request, now).is_ok());
+    assert_eq!(authenticator.verify(&request, now), Err(Rejection::Replay));
+}
+
+#[test]
+fn stale() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator();
+
+    let unauthorized = authenticator.authenticate(&register(), now).unwrap_err();
+    let request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+
+    let later = now + Duration::from_secs(301);
+    assert_eq!(authenticator.verify(&request, later), Err(Rejection::Stale));
+    let unauthorized = authenticator.authenticate(&request, later).unwrap_err();
+    let challenge = unauthorized
+        .www_authenticate_header()
+        .unwrap()
+        .typed()
+        .unwrap();
+    assert_eq!(challenge.stale, Some("true".into()));
+}
+
+#[test]
+fn rejections() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator();
+    let unauthorized = authenticator.authenticate(&register(), now).unwrap_err();
+
+    let request = DigestClient::new("bob", "wrong")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+    assert_eq!(
+        authenticator.verify(&request, now),
+        Err(Rejection::InvalidResponse)
+    );
+
+    let request = DigestClient::new("alice", "zanzibar")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+    assert_eq!(
+        authenticator.verify(&request, now),
+        Err(Rejection::UnknownUser)
+    );
+
+    let mut forged = unauthorized.clone();
+    let mut challenge = forged.www_authenticate_header().unwrap().typed().unwrap();
+    challenge.nonce = challenge
+        .nonce
+        .replacen(|c: char| c.is_ascii_hexdigit(), "0", 1);
+    forged.headers.unique_push(challenge.into());
+    let request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &forged)
+        .unwrap();
+    assert_eq!(
+        authenticator.verify(&request, now),
+        Err(Rejection::InvalidNonce)
+    );
+
+    let other = DigestAuthenticator::new("biloxi.com", "other secret", credentials)
+        .authenticate(&register(), now)
+        .unwrap_err();
+    let request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &other)
+        .unwrap();
+    assert_eq!(
+        authenticator.verify(&request, now),
+        Err(Rejection::InvalidNonce)
+    );
+
+    let mut request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+    request.uri = rsip::Uri::try_from("sip:atlanta.com").unwrap();
+    assert_eq!(authenticator.verify(&request, now), Err(Rejection::Invalid));
+
+    assert_eq!(
+        authenticator.verify(&register(), now),
+        Err(Rejection::Missing)
+    );
+}
+
+#[test]
+fn equivalent_request_uri() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator();
+
+    let unauthorized = authenticator.authenticate(&register(), now).unwrap_err();
+    let mut request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &unauthorized)
+        .unwrap();
+    request.uri = rsip::Uri::try_from("SIP:BILOXI.COM").unwrap();
+    assert_eq!(authenticator.verify(&request, now), Ok(String::from("bob")));
+}
+
+#[test]
+fn proxy() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator().proxy();
+
+    let required = authenticator.authenticate(&register(), now).unwrap_err();
+    assert_eq!(
+        required.status_code,
+        StatusCode::ProxyAuthenticationRequired
+    );
+    assert!(required
+        .headers
+        .iter()
+        .any(|header| matches!(header, Header::ProxyAuthenticate(_))));
+
+    let request = DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &required)
+        .unwrap();
+    assert!(request.authorization_header().is_none());
+    assert_eq!(authenticator.verify(&request, now), Ok(String::from("bob")));
+}
+
+#[test]
+fn session_algorithm_with_auth_int() {
+    let now = SystemTime::now();
+    let mut authenticator = authenticator()
+        .with_algorithm(Algorithm::Sha256Sess)
+        .with_qop(vec![Qop::AuthInt]);
+    let mut request = register();
+    request.body = b"v=0\r\n".to_vec();
+
+    let unauthorized = authenticator.authenticate(&request, now).unwrap_err();
+    let mut client = DigestClient::new("bob", "zanzibar");
+    let request = client.authorize(&request, &unauthorized).unwrap();
+    assert_eq!(authenticator.verify(&request, now), Ok(String::from("bob")));
+
+    let mut request = client.authorize(&request, &unauthorized).unwrap();
+    request.body = b"v=1\r\n".to_vec();
+    assert_eq!(
+        authenticator.verify(&request, now),
+        Err(Rejection::InvalidResponse)
+    );
+}
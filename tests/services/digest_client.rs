@@ -0,0 +1,287 @@
+use rsip::{
+    headers::{
+        auth::{Algorithm, AuthQop},
+        untyped::ToTypedHeader,
+        UntypedHeader,
+    },
+    message::HeadersExt,
+    services::{DigestClient, DigestGenerator},
+    Header, Request, Response, StatusCode,
+};
+use std::convert::TryFrom;
+
+fn register() -> Request {
+    Request::try_from(concat!(
+        "REGISTER sip:biloxi.com SIP/2.0\r\n",
+        "Via: SIP/2.0/UDP bobspc.biloxi.com:5060;branch=z9hG4bKnashds7\r\n",
+        "Max-Forwards: 70\r\n",
+        "To: Bob <sip:bob@biloxi.com>\r\n",
+        "From: Bob <sip:bob@biloxi.com>;tag=456248\r\n",
+        "Call-ID: 843817637684230@998sdasdh09\r\n",
+        "CSeq: 1826 REGISTER\r\n",
+        "Content-Length: 0\r\n\r\n"
+    ))
+    .unwrap()
+}
+
+fn challenge(status_code: StatusCode, challenges: &[&str]) -> Response {
+    let mut response = register().response(status_code.clone());
+    for challenge in challenges {
+        response.headers.push(match status_code {
+            StatusCode::ProxyAuthenticationRequired => {
+                Header::ProxyAuthenticate((*challenge).into())
+            }
+            _ => Header::WwwAuthenticate((*challenge).into()),
+        });
+    }
+    response
+}
+
+#[test]
+fn authorize() -> Result<(), rsip::Error> {
+    let request = register();
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", opaque=\"5ccc\", qop=\"auth\""],
+    );
+
+    let mut client = DigestClient::new("bob", "zanzibar");
+    let retried = client.authorize(&request, &response)?;
+
+    let authorization = retried.authorization_header().unwrap().typed()?;
+    assert_eq!(authorization.username, "bob");
+    assert_eq!(authorization.realm, "biloxi.com");
+    assert_eq!(authorization.nonce, "ea9c8e88df84f1cec4341ae6cbe5a359");
+    assert_eq!(authorization.uri, request.uri);
+    assert_eq!(authorization.opaque, Some("5ccc".into()));
+    assert!(matches!(
+        authorization.qop,
+        Some(AuthQop::Auth { nc: 1, .. })
+    ));
+    assert!(
+        DigestGenerator::from(&authorization, "zanzibar", &retried.method)
+            .verify(&authorization.response)
+    );
+
+    assert_eq!(retried.cseq_header()?.seq()?, 1827);
+    assert_ne!(
+        retried.via_header()?.branch()?,
+        request.via_header()?.branch()?
+    );
+    assert_eq!(retried.call_id_header()?, request.call_id_header()?);
+
+    Ok(())
+}
+
+#[test]
+fn authorize_increments_nonce_count() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", qop=\"auth\""],
+    );
+
+    let mut client = DigestClient::new("bob", "zanzibar");
+    let mut request = client.authorize(&register(), &response)?;
+    for _ in 0..9 {
+        request = client.authorize(&request, &response)?;
+    }
+
+    assert_eq!(request.headers.get_all("Authorization").len(), 1);
+    let authorization = request.authorization_header().unwrap();
+    assert!(authorization.value().contains("nc=0000000a"));
+    assert!(matches!(
+        authorization.typed()?.qop,
+        Some(AuthQop::Auth { nc: 10, .. })
+    ));
+    assert_eq!(request.cseq_header()?.seq()?, 1836);
+
+    Ok(())
+}
+
+#[test]
+fn authorize_nonce_count_beyond_255() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", qop=\"auth\""],
+    );
+
+    let mut client = DigestClient::new("bob", "zanzibar");
+    let mut request = register();
+    for _ in 0..256 {
+        request = client.authorize(&request, &response)?;
+    }
+
+    let authorization = request.authorization_header().unwrap();
+    assert!(authorization.value().contains("nc=00000100"));
+    assert!(matches!(
+        authorization.typed()?.qop,
+        Some(AuthQop::Auth { nc: 256, .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn authorize_without_qop() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\""],
+    );
+
+    let retried = DigestClient::new("bob", "zanzibar").authorize(&register(), &response)?;
+
+    let authorization = retried.authorization_header().unwrap().typed()?;
+    assert_eq!(authorization.qop, None);
+    assert!(
+        DigestGenerator::from(&authorization, "zanzibar", &retried.method)
+            .verify(&authorization.response)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn picks_strongest_supported_challenge() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &[
+            "Digest realm=\"biloxi.com\", nonce=\"md5nonce\", algorithm=MD5, qop=\"auth\"",
+            "Digest realm=\"biloxi.com\", nonce=\"sha256nonce\", algorithm=SHA256, qop=\"auth\"",
+            "Basic realm=\"biloxi.com\"",
+        ],
+    );
+
+    let challenge = DigestClient::new("bob", "zanzibar").challenge(&response)?;
+    assert_eq!(challenge.algorithm, Some(Algorithm::Sha256));
+    assert_eq!(challenge.nonce, "sha256nonce");
+
+    let client = DigestClient::new("bob", "zanzibar").with_algorithms(vec![Algorithm::Md5]);
+    assert_eq!(client.challenge(&response)?.nonce, "md5nonce");
+
+    let client = DigestClient::new("bob", "zanzibar").with_algorithms(vec![]);
+    assert!(client.challenge(&response).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn authorize_proxy() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::ProxyAuthenticationRequired,
+        &["Digest realm=\"atlanta.com\", nonce=\"wf84f1ceczx41ae6cbe5aea9c8e88d359\", qop=\"auth\""],
+    );
+
+    let mut client = DigestClient::new("bob", "zanzibar");
+    let retried = client.authorize(&register(), &response)?;
+    let retried = client.authorize(&retried, &response)?;
+
+    assert!(retried.authorization_header().is_none());
+    let proxy_authorizations = retried.headers.get_all("Proxy-Authorization");
+    assert_eq!(proxy_authorizations.len(), 1);
+    match proxy_authorizations[0] {
+        Header::ProxyAuthorization(header) => {
+            let authorization = header.typed()?.0;
+            assert_eq!(authorization.realm, "atlanta.com");
+            assert!(matches!(
+                authorization.qop,
+                Some(AuthQop::Auth { nc: 2, .. })
+            ));
+            assert!(
+                DigestGenerator::from(&authorization, "zanzibar", &retried.method)
+                    .verify(&authorization.response)
+            );
+        }
+        header => panic!("unexpected header: {}", header),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn authorize_not_a_challenge() {
+    let response = register().response(StatusCode::OK);
+
+    assert!(DigestClient::new("bob", "zanzibar")
+        .authorize(&register(), &response)
+        .is_err());
+}
+
+#[test]
+fn authorize_with_userhash() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", algorithm=SHA-512-256, qop=\"auth\", userhash=true"],
+    );
+
+    let retried = DigestClient::new("bob", "zanzibar").authorize(&register(), &response)?;
+
+    let authorization = retried.authorization_header().unwrap().typed()?;
+    assert_eq!(authorization.algorithm, Some(Algorithm::Sha512256));
+    assert_eq!(authorization.userhash, Some(true));
+    let generator = DigestGenerator {
+        username: "bob",
+        ..DigestGenerator::from(&authorization, "zanzibar", &retried.method)
+    };
+    assert_eq!(authorization.username, generator.userhash());
+    assert!(generator.verify(&authorization.response));
+
+    Ok(())
+}
+
+#[test]
+fn select_challenge() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &[
+            "Digest realm=\"biloxi.com\", nonce=\"md5nonce\", qop=\"auth\", Digest realm=\"biloxi.com\", nonce=\"sha256nonce\", algorithm=SHA-256, qop=\"auth\"",
+            "Digest realm=\"biloxi.com\", nonce=\"sessnonce\", algorithm=SHA-256-sess, qop=\"auth\"",
+        ],
+    );
+    let challenges = response.www_authenticate_challenges();
+
+    let select = |algorithms: &[Algorithm]| {
+        rsip::services::select_challenge(&challenges, algorithms)
+            .map(|challenge| challenge.nonce.clone())
+    };
+    assert_eq!(
+        select(&[Algorithm::Sha256, Algorithm::Md5]),
+        Some("sha256nonce".into())
+    );
+    assert_eq!(
+        select(&[Algorithm::Sha256Sess, Algorithm::Sha256]),
+        Some("sessnonce".into())
+    );
+    assert_eq!(
+        select(&[Algorithm::Sha512256, Algorithm::Md5]),
+        Some("md5nonce".into())
+    );
+    assert_eq!(select(&[Algorithm::Sha512256]), None);
+
+    let challenge = DigestClient::new("bob", "zanzibar").challenge(&response)?;
+    assert_eq!(challenge.nonce, "sha256nonce");
+
+    Ok(())
+}
+
+#[test]
+fn authorize_prefers_auth_qop() -> Result<(), rsip::Error> {
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", qop=\"auth-int,auth\""],
+    );
+
+    let retried = DigestClient::new("bob", "zanzibar").authorize(&register(), &response)?;
+    let authorization = retried.authorization_header().unwrap().typed()?;
+    assert!(matches!(authorization.qop, Some(AuthQop::Auth { .. })));
+
+    let response = challenge(
+        StatusCode::Unauthorized,
+        &["Digest realm=\"biloxi.com\", nonce=\"ea9c8e88df84f1cec4341ae6cbe5a359\", qop=\"auth-int\""],
+    );
+
+    let retried = DigestClient::new("bob", "zanzibar").authorize(&register(), &response)?;
+    let authorization = retried.authorization_header().unwrap().typed()?;
+    assert!(matches!(authorization.qop, Some(AuthQop::AuthInt { .. })));
+
+    Ok(())
+}
@@ -1 +1,3 @@
 pub mod auth;
+pub mod digest_authenticator;
+pub mod digest_client;
@@ -0,0 +1,236 @@
+use super::{request, response};
+use rsip::{
+    transaction::{Action, ClientTransaction, State, Timer, TimerConfig},
+    Method,
+};
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn started(method: &str, transport: &str) -> ClientTransaction {
+    let mut transaction = ClientTransaction::new(request(method, transport)).unwrap();
+    transaction.start();
+    transaction
+}
+
+#[test]
+fn invite_retransmissions() {
+    let mut transaction = ClientTransaction::new(request("INVITE", "UDP")).unwrap();
+
+    assert_eq!(transaction.state(), State::Calling);
+    assert_eq!(
+        transaction.start(),
+        vec![
+            Action::Send(request("INVITE", "UDP").into()),
+            Action::StartTimer(Timer::B, ms(32000)),
+            Action::StartTimer(Timer::A, ms(500)),
+        ]
+    );
+    assert_eq!(
+        transaction.on_timer(Timer::A),
+        vec![
+            Action::Send(request("INVITE", "UDP").into()),
+            Action::StartTimer(Timer::A, ms(1000)),
+        ]
+    );
+    assert_eq!(
+        transaction.on_timer(Timer::A)[1],
+        Action::StartTimer(Timer::A, ms(2000))
+    );
+    assert_eq!(
+        transaction.on_timer(Timer::B),
+        vec![Action::Timeout, Action::Terminated]
+    );
+    assert_eq!(transaction.state(), State::Terminated);
+}
+
+#[test]
+fn invite_over_reliable_transport() {
+    let mut transaction = ClientTransaction::new(request("INVITE", "TCP")).unwrap();
+
+    assert_eq!(
+        transaction.start(),
+        vec![
+            Action::Send(request("INVITE", "TCP").into()),
+            Action::StartTimer(Timer::B, ms(32000)),
+        ]
+    );
+
+    let actions = transaction
+        .on_response(response("486 Busy Here", "INVITE"))
+        .unwrap();
+    assert_eq!(actions[0], Action::CancelTimer(Timer::B));
+    assert_eq!(actions.last(), Some(&Action::Terminated));
+    assert_eq!(transaction.state(), State::Terminated);
+}
+
+#[test]
+fn invite_non_2xx() {
+    let mut transaction = started("INVITE", "UDP");
+
+    assert_eq!(
+        transaction
+            .on_response(response("180 Ringing", "INVITE"))
+            .unwrap(),
+        vec![
+            Action::CancelTimer(Timer::A),
+            Action::CancelTimer(Timer::B),
+            Action::Deliver(response("180 Ringing", "INVITE").into()),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Proceeding);
+
+    let ack = request("INVITE", "UDP")
+        .ack(&response("486 Busy Here", "INVITE"))
+        .unwrap();
+    assert_eq!(ack.method, Method::Ack);
+    assert_eq!(
+        transaction
+            .on_response(response("486 Busy Here", "INVITE"))
+            .unwrap(),
+        vec![
+            Action::Deliver(response("486 Busy Here", "INVITE").into()),
+            Action::Send(ack.clone().into()),
+            Action::StartTimer(Timer::D, ms(32000)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Completed);
+
+    assert_eq!(
+        transaction
+            .on_response(response("486 Busy Here", "INVITE"))
+            .unwrap(),
+        vec![Action::Send(ack.into())]
+    );
+    assert_eq!(transaction.on_timer(Timer::A), vec![]);
+    assert_eq!(transaction.on_timer(Timer::D), vec![Action::Terminated]);
+}
+
+#[test]
+fn invite_2xx() {
+    let mut transaction = started("INVITE", "UDP");
+
+    assert_eq!(
+        transaction
+            .on_response(response("200 OK", "INVITE"))
+            .unwrap(),
+        vec![
+            Action::CancelTimer(Timer::A),
+            Action::CancelTimer(Timer::B),
+            Action::Deliver(response("200 OK", "INVITE").into()),
+            Action::StartTimer(Timer::M, ms(32000)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Accepted);
+    assert_eq!(
+        transaction
+            .on_response(response("200 OK", "INVITE"))
+            .unwrap(),
+        vec![Action::Deliver(response("200 OK", "INVITE").into())]
+    );
+    assert_eq!(transaction.on_timer(Timer::M), vec![Action::Terminated]);
+}
+
+#[test]
+fn invite_non_2xx_custom_timer_d() {
+    let mut transaction = ClientTransaction::new(request("INVITE", "UDP"))
+        .unwrap()
+        .with_timers(TimerConfig {
+            timer_d: ms(64000),
+            ..Default::default()
+        });
+    transaction.start();
+
+    let actions = transaction
+        .on_response(response("486 Busy Here", "INVITE"))
+        .unwrap();
+    assert_eq!(
+        actions.last(),
+        Some(&Action::StartTimer(Timer::D, ms(64000)))
+    );
+}
+
+#[test]
+fn non_invite_retransmissions() {
+    let mut transaction = ClientTransaction::new(request("OPTIONS", "UDP"))
+        .unwrap()
+        .with_timers(TimerConfig {
+            t1: ms(100),
+            t2: ms(400),
+            t4: ms(1000),
+            ..Default::default()
+        });
+
+    assert_eq!(
+        transaction.start(),
+        vec![
+            Action::Send(request("OPTIONS", "UDP").into()),
+            Action::StartTimer(Timer::F, ms(6400)),
+            Action::StartTimer(Timer::E, ms(100)),
+        ]
+    );
+
+    let intervals = (0..4)
+        .map(|_| transaction.on_timer(Timer::E)[1].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        intervals,
+        vec![
+            Action::StartTimer(Timer::E, ms(200)),
+            Action::StartTimer(Timer::E, ms(400)),
+            Action::StartTimer(Timer::E, ms(400)),
+            Action::StartTimer(Timer::E, ms(400)),
+        ]
+    );
+
+    transaction
+        .on_response(response("100 Trying", "OPTIONS"))
+        .unwrap();
+    assert_eq!(transaction.state(), State::Proceeding);
+    assert_eq!(
+        transaction.on_timer(Timer::E)[1],
+        Action::StartTimer(Timer::E, ms(400))
+    );
+    assert_eq!(
+        transaction.on_timer(Timer::F),
+        vec![Action::Timeout, Action::Terminated]
+    );
+}
+
+#[test]
+fn non_invite_final() {
+    let mut transaction = started("OPTIONS", "UDP");
+
+    assert_eq!(
+        transaction
+            .on_response(response("200 OK", "OPTIONS"))
+            .unwrap(),
+        vec![
+            Action::CancelTimer(Timer::E),
+            Action::CancelTimer(Timer::F),
+            Action::Deliver(response("200 OK", "OPTIONS").into()),
+            Action::StartTimer(Timer::K, ms(5000)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Completed);
+    assert_eq!(
+        transaction
+            .on_response(response("200 OK", "OPTIONS"))
+            .unwrap(),
+        vec![]
+    );
+    assert_eq!(transaction.on_timer(Timer::K), vec![Action::Terminated]);
+}
+
+#[test]
+fn transport_error() {
+    let mut transaction = started("OPTIONS", "TCP");
+
+    assert_eq!(
+        transaction.on_transport_error(),
+        vec![Action::TransportError, Action::Terminated]
+    );
+    assert_eq!(transaction.on_transport_error(), vec![]);
+}
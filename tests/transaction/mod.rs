@@ -0,0 +1,42 @@
+pub mod client;
+pub mod server;
+
+use rsip::{Request, Response};
+use std::convert::TryFrom;
+
+pub fn request(method: &str, transport: &str) -> Request {
+    Request::try_from(format!(
+        concat!(
+            "{method} sip:bob@biloxi.example.com SIP/2.0\r\n",
+            "Via: SIP/2.0/{transport} client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+            "Max-Forwards: 70\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>\r\n",
+            "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+            "CSeq: 1 {method}\r\n",
+            "Contact: <sip:alice@client.atlanta.example.com>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        method = method,
+        transport = transport
+    ))
+    .unwrap()
+}
+
+pub fn response(status: &str, method: &str) -> Response {
+    Response::try_from(format!(
+        concat!(
+            "SIP/2.0 {status}\r\n",
+            "Via: SIP/2.0/UDP client.atlanta.example.com:5060;branch=z9hG4bK74bf9\r\n",
+            "From: Alice <sip:alice@atlanta.example.com>;tag=9fxced76sl\r\n",
+            "To: Bob <sip:bob@biloxi.example.com>;tag=314159\r\n",
+            "Call-ID: 3848276298220188511@atlanta.example.com\r\n",
+            "CSeq: 1 {method}\r\n",
+            "Contact: <sip:bob@client.biloxi.example.com>\r\n",
+            "Content-Length: 0\r\n\r\n"
+        ),
+        status = status,
+        method = method
+    ))
+    .unwrap()
+}
@@ -0,0 +1,171 @@
+use super::{request, response};
+use rsip::{
+    transaction::{Action, ServerTransaction, State, Timer},
+    StatusCode,
+};
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn started(method: &str, transport: &str) -> ServerTransaction {
+    let mut transaction = ServerTransaction::new(request(method, transport)).unwrap();
+    transaction.start();
+    transaction
+}
+
+#[test]
+fn invite_non_2xx() {
+    let mut transaction = ServerTransaction::new(request("INVITE", "UDP")).unwrap();
+    let trying = request("INVITE", "UDP").response(StatusCode::Trying);
+
+    assert_eq!(transaction.state(), State::Proceeding);
+    assert_eq!(
+        transaction.start(),
+        vec![
+            Action::Deliver(request("INVITE", "UDP").into()),
+            Action::Send(trying.clone().into()),
+        ]
+    );
+    assert_eq!(
+        transaction.on_request(request("INVITE", "UDP")),
+        vec![Action::Send(trying.into())]
+    );
+
+    let ringing = response("180 Ringing", "INVITE");
+    assert_eq!(
+        transaction.send_response(ringing.clone()),
+        vec![Action::Send(ringing.clone().into())]
+    );
+    assert_eq!(
+        transaction.on_request(request("INVITE", "UDP")),
+        vec![Action::Send(ringing.into())]
+    );
+
+    let busy = response("486 Busy Here", "INVITE");
+    assert_eq!(
+        transaction.send_response(busy.clone()),
+        vec![
+            Action::Send(busy.clone().into()),
+            Action::StartTimer(Timer::H, ms(32000)),
+            Action::StartTimer(Timer::G, ms(500)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Completed);
+    assert_eq!(
+        transaction.on_timer(Timer::G),
+        vec![
+            Action::Send(busy.clone().into()),
+            Action::StartTimer(Timer::G, ms(1000)),
+        ]
+    );
+    assert_eq!(
+        transaction.on_request(request("INVITE", "UDP")),
+        vec![Action::Send(busy.into())]
+    );
+
+    assert_eq!(
+        transaction.on_request(request("ACK", "UDP")),
+        vec![
+            Action::CancelTimer(Timer::H),
+            Action::CancelTimer(Timer::G),
+            Action::StartTimer(Timer::I, ms(5000)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Confirmed);
+    assert_eq!(transaction.on_request(request("ACK", "UDP")), vec![]);
+    assert_eq!(transaction.on_timer(Timer::I), vec![Action::Terminated]);
+}
+
+#[test]
+fn invite_ack_timeout() {
+    let mut transaction = started("INVITE", "TCP");
+
+    assert_eq!(
+        transaction.send_response(response("486 Busy Here", "INVITE")),
+        vec![
+            Action::Send(response("486 Busy Here", "INVITE").into()),
+            Action::StartTimer(Timer::H, ms(32000)),
+        ]
+    );
+    assert_eq!(
+        transaction.on_timer(Timer::H),
+        vec![Action::Timeout, Action::Terminated]
+    );
+}
+
+#[test]
+fn invite_2xx() {
+    let mut transaction = started("INVITE", "UDP");
+    let ok = response("200 OK", "INVITE");
+
+    assert_eq!(
+        transaction.send_response(ok.clone()),
+        vec![
+            Action::Send(ok.clone().into()),
+            Action::StartTimer(Timer::L, ms(32000)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Accepted);
+    assert_eq!(transaction.on_request(request("INVITE", "UDP")), vec![]);
+    assert_eq!(
+        transaction.send_response(ok.clone()),
+        vec![Action::Send(ok.into())]
+    );
+    assert_eq!(
+        transaction.on_request(request("ACK", "UDP")),
+        vec![Action::Deliver(request("ACK", "UDP").into())]
+    );
+    assert_eq!(transaction.on_timer(Timer::L), vec![Action::Terminated]);
+}
+
+#[test]
+fn non_invite() {
+    let mut transaction = ServerTransaction::new(request("OPTIONS", "UDP")).unwrap();
+
+    assert_eq!(transaction.state(), State::Trying);
+    assert_eq!(
+        transaction.start(),
+        vec![Action::Deliver(request("OPTIONS", "UDP").into())]
+    );
+    assert_eq!(transaction.on_request(request("OPTIONS", "UDP")), vec![]);
+
+    let ok = response("200 OK", "OPTIONS");
+    assert_eq!(
+        transaction.send_response(ok.clone()),
+        vec![
+            Action::Send(ok.clone().into()),
+            Action::StartTimer(Timer::J, ms(32000)),
+        ]
+    );
+    assert_eq!(transaction.state(), State::Completed);
+    assert_eq!(
+        transaction.on_request(request("OPTIONS", "UDP")),
+        vec![Action::Send(ok.into())]
+    );
+    assert_eq!(
+        transaction.send_response(response("500 Server Internal Error", "OPTIONS")),
+        vec![]
+    );
+    assert_eq!(transaction.on_timer(Timer::J), vec![Action::Terminated]);
+}
+
+#[test]
+fn non_invite_over_reliable_transport() {
+    let mut transaction = started("OPTIONS", "TCP");
+
+    assert_eq!(
+        transaction.send_response(response("200 OK", "OPTIONS")),
+        vec![
+            Action::Send(response("200 OK", "OPTIONS").into()),
+            Action::Terminated,
+        ]
+    );
+    assert_eq!(transaction.state(), State::Terminated);
+}
+
+#[test]
+fn ack_does_not_create_transaction() {
+    assert!(ServerTransaction::new(request("ACK", "UDP")).is_err());
+}